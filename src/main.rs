@@ -1,16 +1,43 @@
-use std::{fs, path::Path};
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
 
-use anyhow::{anyhow, Ok, Result};
-use clap::{Parser, ValueEnum};
-use solutions::SOLUTIONS;
+use anyhow::{anyhow, Context, Ok, Result};
+use clap::{Parser, Subcommand, ValueEnum};
+use itertools::Itertools;
+use solutions::REGISTRY;
 
 use crate::{
+    runner::{
+        baseline::{add_report, Baseline, Timings},
+        bench::{bench, print_bench, BenchReport},
+        examples::load_examples,
+        inputs::{lint, InputProfile, Manifest},
+        isolate::{catch_panic, parse_duration, run_isolated},
+        pool::{job_count, run_in_order},
+        report::{OutputFormat, Reporter},
+        scaffold::{load_user_templates, new_day, pick_template},
+        select::DaySet,
+        verify::{check, print_verdicts, Answers, Status, Verdict},
+    },
     solutions::templates::{
         linear_template::ExampleSolutionLinear, simultaneous_template::ExampleSolutionSimultaneous,
     },
-    utils::solver_types::{solve_linear, solve_simultaneous},
+    utils::{
+        progress::Cancel,
+        solver_types::{
+            parse_param, solve_linear, solve_simultaneous, DayReport, ParamOverride, Parts,
+            RawParams, Solution, SolveOptions, Solved,
+        },
+    },
 };
 
+pub mod runner;
 pub mod solutions;
 pub mod utils;
 
@@ -19,119 +46,730 @@ enum RunMode {
     Example,
     Single,
     All,
+    Bench,
+    Verify,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Start a new day: create its solution file from a template, register it, and add empty placeholders for its input and first example. Run from the root of the repository.
+    New {
+        /// The day to start.
+        #[arg(long, short, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Which year the day is from. Defaults to the latest year with any solutions.
+        #[arg(long, short)]
+        year: Option<u16>,
+        /// Which template to start from: linear, simultaneous (both parts solved together), grid (loads a 2D grid), vm (parses and runs a program), or one of your own from --templates-dir.
+        #[arg(long, short, default_value = "linear")]
+        kind: String,
+        /// Where to find your own templates, named <name>.template. They're filled in like the built-in ones in templates/, and replace a built-in one of the same name.
+        #[arg(long, default_value = "templates/user")]
+        templates_dir: String,
+    },
 }
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
     #[arg(long, short, default_value = "example")]
-    /// Example: run an example. Single: run a single day's solution. All: Run all solutions sequentially.
+    /// Example: run an example, or a day's worked examples if --day is given. Single: run a single day's solution. All: Run all solutions sequentially. Bench: repeatedly run a single day's solution and report timing statistics. Verify: run all solutions and check them against recorded answers.
     mode: RunMode,
-    /// Specify which day's solution to run - only used when --mode is single.
-    #[arg(long, short, required_if_eq("mode", "single"))]
+    /// Which year's solutions to run. Defaults to the latest year with any solutions.
+    #[arg(long, short)]
+    year: Option<u16>,
+    /// Specify which day's solution to run - only used when --mode is single, bench or example.
+    #[arg(long, short, required_if_eq_any([("mode", "single"), ("mode", "bench")]))]
     day: Option<usize>,
-    /// Specify the filepath to the day's input - only used when --mode is single
+    /// Which days to run when --mode is all or verify, e.g. 1-5,9,15-20. Defaults to every day.
+    #[arg(long)]
+    days: Option<DaySet>,
+    /// Days to leave out when --mode is all or verify, e.g. 6,14.
+    #[arg(long)]
+    except: Option<DaySet>,
+    /// Only solve this part. Part 1 is still solved for part 2 when a day's parts are solved in order.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Specify the filepath to the day's input, or - to read it from stdin - only used when --mode is single or bench
     #[arg(long, short)]
     input: Option<String>,
+    /// Pass the day's input inline instead of from a file - only used when --mode is single or bench
+    #[arg(long, conflicts_with = "input")]
+    input_text: Option<String>,
+    /// Where to find each year's worked examples, named <year>/day{day}_{number}.txt, and their answers.toml.
+    #[arg(long, default_value = "examples")]
+    examples_dir: String,
+    /// Where to find each year's inputs, named <year>/input_{day}.txt.
+    #[arg(long, default_value = "inputs")]
+    inputs_dir: String,
+    /// Use the inputs in <inputs-dir>/<year>/<profile> instead. Give several, e.g. alice,bob, to run every day on each of them when --mode is all or verify.
+    #[arg(long, value_delimiter = ',')]
+    profile: Vec<String>,
+    /// How to write out answers and timings.
+    #[arg(long, short, value_enum, default_value_t)]
+    format: OutputFormat,
+    /// How many days to solve at once when --mode is all. 0 uses every available core.
+    #[arg(long, short, default_value_t = 1)]
+    jobs: usize,
+    /// How many timed runs to make when --mode is bench.
+    #[arg(long, default_value_t = 10)]
+    iterations: usize,
+    /// How many untimed runs to make before timing when --mode is bench.
+    #[arg(long, default_value_t = 2)]
+    warmup: usize,
+    /// Where to find timing baselines. If the file exists, timings are compared to it after a run. Defaults to bench/<year>/baseline.json.
+    #[arg(long)]
+    baseline: Option<String>,
+    /// Save this run's timings to the baseline file, replacing the timings of any day that was run.
+    #[arg(long)]
+    save_baseline: bool,
+    /// Record the checksum of every input that was run in the manifest.toml alongside it, e.g. after recording its answers. Inputs that change afterwards are flagged.
+    #[arg(long, conflicts_with_all = ["input", "input_text"])]
+    save_manifest: bool,
+    /// How much slower than the baseline, in percent, a phase can get before it's flagged.
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f32,
+    /// Where to find the recorded answers for each day's input - only used when --mode is verify. Defaults to answers.toml alongside each profile's inputs.
+    #[arg(long)]
+    answers: Option<String>,
+    /// Give up on a day that runs for longer than this, e.g. 500ms, 10s or 2m, and carry on with the rest. Not used when --mode is bench.
+    #[arg(long, value_parser = parse_duration)]
+    timeout: Option<Duration>,
+    /// Override one of a day's puzzle parameters, e.g. --param size=6. Can be given more than once. When running several days, say which day it's for, e.g. --param 11.blinks=40.
+    #[arg(long = "param", value_parser = parse_param)]
+    params: Vec<ParamOverride>,
+    /// Don't show the progress of long-running solutions. It's only ever shown on a terminal, with text output and one job at a time, and never when --mode is bench.
+    #[arg(long, short)]
+    quiet: bool,
 }
 
-fn main() {
+fn main() -> ExitCode {
     let args = Args::parse();
 
+    match run(&args) {
+        Result::Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error: {:?}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// run the requested mode. Any failure, including a single failing day, is returned as an error
+fn run(args: &Args) -> Result<()> {
+    if let Some(Command::New {
+        day,
+        year,
+        kind,
+        templates_dir,
+    }) = &args.command
+    {
+        let year = year
+            .or(REGISTRY.latest_year())
+            .ok_or_else(|| anyhow!("Pass --year for the first day of a year"))?;
+        let template = pick_template(kind, &load_user_templates(Path::new(templates_dir))?)?;
+        return new_day(
+            year,
+            (*day).into(),
+            &template,
+            Path::new(&args.inputs_dir),
+            Path::new(&args.examples_dir),
+        );
+    }
+
+    let mut reporter = Reporter::new(args.format);
+    let mut timings = Timings::new();
+    let options = solve_options(args, args.day, Default::default());
+    let year = selected_year(args)?;
+
     match args.mode {
-        RunMode::Example => run_example(),
+        RunMode::Example => match args.day {
+            Some(day) => {
+                let verdicts = run_day_examples(args, year, day)
+                    .with_context(|| format!("Failed to run examples for Day {}", day))?;
+                print_verdicts(args.format, &verdicts);
+                return check_verdicts(&verdicts, "examples");
+            }
+            None => run_example(&mut reporter, &options)?,
+        },
+        RunMode::Verify => {
+            let runs = select_runs(args, year)?;
+            let manifests = load_manifests(&runs)?;
+            let verdicts = run_verify(args, &runs, &manifests)?;
+            if args.save_manifest {
+                save_manifests(manifests, &runs)?;
+            }
+            print_verdicts(args.format, &verdicts);
+            return check_verdicts(&verdicts, "solutions");
+        }
         RunMode::All => {
-            let result = run_all();
-            if let Err(err) = result {
-                println!("Failed to run solutions. Reason: {}", err)
+            let runs = select_runs(args, year)?;
+            let manifests = load_manifests(&runs)?;
+            run_all(&mut reporter, &mut timings, &runs, &manifests, args);
+            if args.save_manifest {
+                save_manifests(manifests, &runs)?;
             }
         }
         RunMode::Single => {
-            let result = run_single(args.day.unwrap(), args.input);
-
-            if let Err(err) = result {
-                println!(
-                    "Failed to run solution for Day {}. Reason: {}",
-                    args.day.unwrap(),
-                    err
-                )
+            let day = args.day.unwrap();
+            let runs = [(single_profile(args, year)?, day)];
+            let manifests = load_manifests(&runs)?;
+            let profile = &runs[0].0;
+
+            let on_solved = reporter.stream();
+            let report = single_input(day, profile, &manifests[profile], args)
+                .and_then(|(input, issues)| {
+                    blame_input(
+                        run_single(year, day, input, &options, args.timeout, on_solved),
+                        &issues,
+                    )
+                })
+                .with_context(|| format!("Failed to run solution for Day {}", day))?;
+
+            let name = profile.name(day);
+            add_report(&mut timings, &name, &report);
+            reporter.report(&name, report);
+
+            if args.save_manifest {
+                save_manifests(manifests, &runs)?;
+            }
+        }
+        RunMode::Bench => {
+            let day = args.day.unwrap();
+            let runs = [(single_profile(args, year)?, day)];
+            let manifests = load_manifests(&runs)?;
+            let profile = &runs[0].0;
+
+            let report = single_input(day, profile, &manifests[profile], args)
+                .and_then(|(input, _)| {
+                    run_bench(&profile.name(day), year, day, input, &options, args)
+                })
+                .with_context(|| format!("Failed to benchmark Day {}", day))?;
+
+            timings = report.timings();
+            print_bench(args.format, &report);
+
+            if args.save_manifest {
+                save_manifests(manifests, &runs)?;
             }
         }
     }
+
+    let failures = reporter.failures();
+
+    if !matches!(args.mode, RunMode::Bench) {
+        reporter.finish();
+    }
+
+    if !timings.is_empty() {
+        check_baseline(args, year, timings).context("Failed to check baseline")?;
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(anyhow!(
+            "{} solutions failed: {}",
+            failures.len(),
+            failures.join(", ")
+        ))
+    }
+}
+
+/// the parts to solve `day` with and its parameters. Params given with --param for the day override `params`.
+/// Progress is only shown where it can't get mixed up with other output or timings
+fn solve_options(args: &Args, day: Option<usize>, mut params: RawParams) -> SolveOptions {
+    params.extend(
+        args.params
+            .iter()
+            .filter(|p| p.applies_to(day))
+            .map(|p| (p.key.clone(), p.value.clone())),
+    );
+
+    SolveOptions {
+        parts: Parts::only(args.part),
+        params,
+        progress: !args.quiet
+            && args.format == OutputFormat::Text
+            && job_count(args.jobs) == 1
+            && !matches!(args.mode, RunMode::Bench),
+        cancel: Cancel::default(),
+    }
+}
+
+/// fail if a --param without a day would be passed to several days, as no two days share their parameters
+fn check_params_scoped(args: &Args, runs: &[(InputProfile, usize)]) -> Result<()> {
+    let several_days = runs.iter().map(|(_, day)| day).dedup().count() > 1;
+    match args.params.iter().find(|p| p.day.is_none()) {
+        Some(p) if several_days => Err(anyhow!(
+            "Say which day --param {} is for when running several days, e.g. --param {}.{}={}",
+            p.key,
+            runs[0].1,
+            p.key,
+            p.value
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// fail if any verdict didn't pass. Missing answers aren't a failure
+fn check_verdicts(verdicts: &[Verdict], what: &str) -> Result<()> {
+    let failed = verdicts
+        .iter()
+        .filter(|v| matches!(v.status, Status::Fail | Status::Error | Status::Timeout))
+        .count();
+
+    if failed > 0 {
+        return Err(anyhow!("{} {} failed verification", failed, what));
+    }
+    Ok(())
+}
+
+/// the days picked by --days and --except, in order, for each of the --profile inputs
+fn select_runs(args: &Args, year: u16) -> Result<Vec<(InputProfile, usize)>> {
+    let solved = DaySet::all(REGISTRY.days(year));
+    let days = args.days.clone().unwrap_or_else(|| solved.clone());
+    days.check(&solved)?;
+
+    let days = match &args.except {
+        Some(except) => days.without(except),
+        None => days,
+    };
+
+    let profiles = InputProfile::all(Path::new(&args.inputs_dir), year, &args.profile);
+
+    let runs: Vec<_> = days
+        .days()
+        .into_iter()
+        .flat_map(|day| profiles.iter().map(move |p| (p.clone(), day)))
+        .collect();
+    check_params_scoped(args, &runs)?;
+
+    Ok(runs)
+}
+
+/// the inputs for --mode single or bench, which only take one profile
+fn single_profile(args: &Args, year: u16) -> Result<InputProfile> {
+    match args.profile.as_slice() {
+        [] => Ok(InputProfile::new(Path::new(&args.inputs_dir), year, None)),
+        [profile] => Ok(InputProfile::new(
+            Path::new(&args.inputs_dir),
+            year,
+            Some(profile),
+        )),
+        _ => Err(anyhow!(
+            "Only one --profile can be used when --mode is single or bench"
+        )),
+    }
+}
+
+/// compare timings to the baseline, flagging regressions on stderr, then save them if requested
+fn check_baseline(args: &Args, year: u16, timings: Timings) -> Result<()> {
+    let path = match &args.baseline {
+        Some(path) => PathBuf::from(path),
+        None => Path::new("bench")
+            .join(year.to_string())
+            .join("baseline.json"),
+    };
+    let mut baseline = Baseline::load(&path)?;
+
+    let (compared, regressions) = baseline.compare(&timings, args.threshold);
+    if compared > 0 {
+        eprintln!(
+            "\nCompared {} phases against {:?}: {} slower by more than {}%",
+            compared,
+            baseline.path(),
+            regressions.len(),
+            args.threshold
+        );
+        for r in regressions {
+            eprintln!(
+                "  {} {}:\t{:.3}ms -> {:.3}ms (+{:.1}%)",
+                r.name, r.phase, r.baseline_ms, r.current_ms, r.slower_by_pct
+            );
+        }
+    }
+
+    if args.save_baseline {
+        baseline.update(timings);
+        baseline.save()?;
+        eprintln!("Saved timings to {:?}", baseline.path());
+    }
+
+    Ok(())
 }
 
-/// Load a puzzle input from a .txt file
+/// Load a puzzle input from a file, or from stdin if the path is `-`
 fn load_from_file(file_path: &Path) -> Result<String> {
-    if !file_path.is_file()
-        || file_path.extension().is_none()
-        || file_path.extension().unwrap() != "txt"
-    {
+    if file_path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .context("Failed to read input from stdin")?;
+        return Ok(input);
+    }
+
+    if !file_path.is_file() {
         return Err(anyhow!(
             "input path '{:?}' is not valid. Please provide a path to a valid text file.",
             file_path.to_str()
         ));
     }
 
-    match fs::read_to_string(file_path) {
-        Err(error) => Err(anyhow!(
-            "Failed to read file. Reason: {}",
-            error.to_string()
-        )),
-        Result::Ok(text) => Ok(text),
+    fs::read_to_string(file_path).with_context(|| format!("Failed to read file {:?}", file_path))
+}
+
+/// the year given by --year, or the latest one with any solutions
+fn selected_year(args: &Args) -> Result<u16> {
+    let year = args
+        .year
+        .or(REGISTRY.latest_year())
+        .ok_or_else(|| anyhow!("There are no solutions yet"))?;
+
+    if REGISTRY.days(year).is_empty() {
+        return Err(anyhow!(
+            "There are no solutions for {}. Try one of: {}",
+            year,
+            REGISTRY.years().iter().join(", ")
+        ));
     }
+    Ok(year)
+}
+
+/// the solution for a day of `year`, if it's been solved
+fn check_day(year: u16, day: usize) -> Result<&'static dyn Solution> {
+    REGISTRY
+        .get(year, day)
+        .ok_or_else(|| anyhow!("Day '{}' is invalid or not yet solved", day))
+}
+
+/// check a day has a solution and load its input from `path`
+fn load_day(year: u16, day: usize, path: &Path) -> Result<String> {
+    check_day(year, day)?;
+
+    load_from_file(path)
+}
+
+/// the input for --mode single or bench, from --input-text, --input or the profile's file for the day.
+/// Returns the input alongside any problems found with it
+fn single_input(
+    day: usize,
+    profile: &InputProfile,
+    manifest: &Manifest,
+    args: &Args,
+) -> Result<(String, Vec<String>)> {
+    let (source, input, changed) = match (&args.input_text, &args.input) {
+        (Some(text), _) => {
+            check_day(profile.year(), day)?;
+            ("--input-text".to_string(), text.clone(), false)
+        }
+        (None, Some(path)) => {
+            let input = load_day(profile.year(), day, Path::new(path))?;
+            let source = if path == "-" { "stdin" } else { path };
+            (source.to_string(), input, false)
+        }
+        (None, None) => {
+            let path = profile.day_path(day);
+            let input = load_day(profile.year(), day, &path)?;
+            let changed = manifest.changed(&format!("day{:02}", day), &input);
+            (path.display().to_string(), input, changed)
+        }
+    };
+
+    let issues = input_issues(&source, &input, changed);
+    Ok((input, issues))
 }
 
-/// run a single specified day's solution
-fn run_single(day: usize, input_path: Option<String>) -> Result<f32> {
-    if day < 1 || day > SOLUTIONS.len() {
-        return Err(anyhow!("Day '{}' is invalid or not yet solved", day));
+/// anything about an input that might trip up its loader, each printed to stderr as a warning
+fn input_issues(source: &str, input: &str, changed: bool) -> Vec<String> {
+    let mut issues = lint(input);
+    if changed {
+        issues.push("has changed since its checksum was recorded".to_string());
     }
 
-    let unwrapped_path = input_path.unwrap_or(format!("./inputs/input_{:02}.txt", day));
-    let file_path = Path::new(&unwrapped_path);
+    for issue in &issues {
+        eprintln!("Warning: {} {}", source, issue);
+    }
 
-    let input = load_from_file(file_path)?;
+    issues
+}
 
-    SOLUTIONS[day - 1](&input)
+/// if a day failed, point at any problems with its input, which are the likeliest cause
+fn blame_input(result: Result<DayReport>, issues: &[String]) -> Result<DayReport> {
+    match result {
+        Err(err) if !issues.is_empty() => Err(err.context(format!(
+            "possibly because its input {}",
+            issues.join(" and ")
+        ))),
+        res => res,
+    }
 }
 
-/// run all solutions
-fn run_all() -> Result<()> {
-    let mut time_total = 0.0;
+/// load the input manifest of every profile being run
+fn load_manifests(runs: &[(InputProfile, usize)]) -> Result<BTreeMap<InputProfile, Manifest>> {
+    let mut manifests = BTreeMap::new();
+    for (profile, _) in runs {
+        if !manifests.contains_key(profile) {
+            let manifest = Manifest::load(&profile.manifest_path())?;
+            manifests.insert(profile.clone(), manifest);
+        }
+    }
+    Ok(manifests)
+}
 
-    for i in 0..SOLUTIONS.len() {
-        println!("\nDay {:02}:\n", i + 1);
-        time_total += run_single(i + 1, None)?;
+/// record the checksum of every input that was run, for --save-manifest
+fn save_manifests(
+    mut manifests: BTreeMap<InputProfile, Manifest>,
+    runs: &[(InputProfile, usize)],
+) -> Result<()> {
+    for (profile, day) in runs {
+        // days whose input couldn't be read have already been reported
+        if let Result::Ok(input) = fs::read_to_string(profile.day_path(*day)) {
+            if let Some(manifest) = manifests.get_mut(profile) {
+                manifest.record(&format!("day{:02}", day), &input);
+            }
+        }
     }
 
-    println!("\nSolved all problems in: {}ms", time_total);
+    for manifest in manifests.values() {
+        manifest.save()?;
+        eprintln!("Saved input checksums to {:?}", manifest.path());
+    }
 
     Ok(())
 }
 
-fn run_example() {
-    println!("Here's an example of a linear solution:");
-    print!(
-        r"
+/// solve a day, reporting a panic as an error rather than letting it take the runner down
+fn solve_day(year: u16, day: usize, input: &str, options: &SolveOptions) -> Result<DayReport> {
+    let solution = check_day(year, day)?;
+
+    catch_panic(|| solution.solve(input, options))
+}
+
+/// run a single specified day's solution, passing each step to `on_solved` as it's done,
+/// and giving up on it after `timeout`
+fn run_single(
+    year: u16,
+    day: usize,
+    input: String,
+    options: &SolveOptions,
+    timeout: Option<Duration>,
+    mut on_solved: fn(&Solved),
+) -> Result<DayReport> {
+    let solution = check_day(year, day)?;
+    let cancel = Cancel::default();
+    let options = SolveOptions {
+        cancel: cancel.clone(),
+        ..options.clone()
+    };
+
+    run_isolated(timeout, &cancel, move || {
+        solution.solve_streaming(&input, &options, &mut on_solved)
+    })
+}
+
+/// run a single specified day's solution repeatedly
+fn run_bench(
+    name: &str,
+    year: u16,
+    day: usize,
+    input: String,
+    options: &SolveOptions,
+    args: &Args,
+) -> Result<BenchReport> {
+    bench(
+        name,
+        |input| solve_day(year, day, input, options),
+        &input,
+        args.warmup,
+        args.iterations,
+    )
+}
+
+/// load a day's input from a profile and solve it, checking the input against the profile's manifest
+fn run_profile(
+    profile: &InputProfile,
+    manifest: &Manifest,
+    day: usize,
+    options: &SolveOptions,
+    timeout: Option<Duration>,
+) -> Result<DayReport> {
+    let path = profile.day_path(day);
+    let input = load_day(profile.year(), day, &path)?;
+    let changed = manifest.changed(&format!("day{:02}", day), &input);
+    let issues = input_issues(&path.display().to_string(), &input, changed);
+
+    blame_input(
+        run_single(profile.year(), day, input, options, timeout, |_| ()),
+        &issues,
+    )
+}
+
+/// run the given days' solutions, --jobs days at a time. Reports are always printed in day order.
+/// A failing or timed out day is reported and the rest carry on
+fn run_all(
+    reporter: &mut Reporter,
+    timings: &mut Timings,
+    runs: &[(InputProfile, usize)],
+    manifests: &BTreeMap<InputProfile, Manifest>,
+    args: &Args,
+) {
+    let start = Instant::now();
+    let jobs = job_count(args.jobs);
+
+    run_in_order(
+        runs.len(),
+        jobs,
+        |i| {
+            let (profile, day) = &runs[i];
+            let options = solve_options(args, Some(*day), Default::default());
+            run_profile(profile, &manifests[profile], *day, &options, args.timeout)
+        },
+        |i, result| {
+            let (profile, day) = &runs[i];
+            if reporter.is_text() {
+                let title = check_day(profile.year(), *day)
+                    .map(|s| s.info().title)
+                    .unwrap_or_default();
+                match profile.profile() {
+                    Some(p) => println!("\nDay {:02} ({}): {}\n", day, p, title),
+                    None => println!("\nDay {:02}: {}\n", day, title),
+                }
+            }
+            let name = profile.name(*day);
+            match result {
+                Result::Ok(report) => {
+                    add_report(timings, &name, &report);
+                    reporter.report(&name, report);
+                }
+                Err(err) => reporter.fail(&name, &err),
+            }
+            Ok(())
+        },
+    )
+    .expect("reporting a day never fails");
+
+    let wall_ms = start.elapsed().as_secs_f32() * 1000.0;
+    reporter.set_wall_ms(wall_ms);
+
+    if reporter.is_text() {
+        println!("\nSolved all problems in: {}ms", reporter.total_ms());
+        println!("Wall-clock time:\t{}ms (jobs: {})", wall_ms, jobs);
+    }
+}
+
+/// run the given days' solutions and check their answers against those recorded in --answers,
+/// or each profile's own answers
+fn run_verify(
+    args: &Args,
+    runs: &[(InputProfile, usize)],
+    manifests: &BTreeMap<InputProfile, Manifest>,
+) -> Result<Vec<Verdict>> {
+    let mut answers = BTreeMap::new();
+    for (profile, _) in runs {
+        if !answers.contains_key(profile) {
+            let path = match &args.answers {
+                Some(path) => PathBuf::from(path),
+                None => profile.answers_path(),
+            };
+            answers.insert(profile.clone(), Answers::load(&path)?);
+        }
+    }
+
+    let mut verdicts = vec![];
+
+    run_in_order(
+        runs.len(),
+        job_count(args.jobs),
+        |i| {
+            let (profile, day) = &runs[i];
+            let options = solve_options(args, Some(*day), Default::default());
+            run_profile(profile, &manifests[profile], *day, &options, args.timeout)
+        },
+        |i, result| {
+            let (profile, day) = &runs[i];
+            let expected = answers[profile].get(&format!("day{:02}", day));
+            verdicts.push(check(&profile.name(*day), expected, result));
+            Ok(())
+        },
+    )?;
+
+    Ok(verdicts)
+}
+
+/// run a day against each of its worked examples and check the answers.
+/// Only the parts an example has answers for are solved, unless --part says otherwise,
+/// and --param overrides the example's own parameters
+fn run_day_examples(args: &Args, year: u16, day: usize) -> Result<Vec<Verdict>> {
+    check_day(year, day)?;
+
+    let examples = load_examples(Path::new(&args.examples_dir), year, day)?;
+    if examples.is_empty() {
+        return Err(anyhow!(
+            "No examples found in {:?}. Add them as {}/day{:02}_1.txt, {}/day{:02}_2.txt, ...",
+            args.examples_dir,
+            year,
+            day,
+            year,
+            day
+        ));
+    }
+
+    Ok(examples
+        .into_iter()
+        .map(|example| {
+            let parts = example.parts();
+            let mut options = solve_options(args, Some(day), example.params);
+            if args.part.is_none() {
+                options.parts = parts;
+            }
+            let result = run_single(year, day, example.input, &options, args.timeout, |_| ());
+            check(&example.name, Some(&example.expected), result)
+        })
+        .collect())
+}
+
+fn run_example(reporter: &mut Reporter, options: &SolveOptions) -> Result<()> {
+    if reporter.is_text() {
+        println!("Here's an example of a linear solution:");
+        print!(
+            r"
 input: [1,2,3,4,5]
 part 1: sum up these numbers
 part 2: multiply the result of part 1 by the number of numbers in the input
 "
-    );
+        );
+    }
 
-    let solved_in_1 = solve_linear::<ExampleSolutionLinear, _, _, _>("[1,2,3,4,5]").unwrap();
+    let mut on_solved = reporter.stream();
 
-    println!("\nHere's an example of a simultaneous solution:");
-    print!(
-        r"
+    let solved_1 =
+        solve_linear::<ExampleSolutionLinear, _, _, _>("[1,2,3,4,5]", options, &mut on_solved)?;
+    reporter.report("example_linear", solved_1);
+
+    if reporter.is_text() {
+        println!("\nHere's an example of a simultaneous solution:");
+        print!(
+            r"
 input: [6,5,4,2,3,5,8]
 part 1: get the first number that's higher than the previous
 part 2: get the number after the first number that's higher than the previous
 "
-    );
+        );
+    }
+
+    let solved_2 = solve_simultaneous::<ExampleSolutionSimultaneous, _, _, _>(
+        "[6,5,4,2,3,5,8]",
+        options,
+        &mut on_solved,
+    )?;
+    reporter.report("example_simultaneous", solved_2);
 
-    let solved_in_2 =
-        solve_simultaneous::<ExampleSolutionSimultaneous, _, _, _>("[6,5,4,2,3,5,8]").unwrap();
+    if reporter.is_text() {
+        println!("Example time:\t\t{}ms", reporter.total_ms());
+    }
 
-    println!("Example time:\t\t{}ms", solved_in_1 + solved_in_2);
+    Ok(())
 }
@@ -0,0 +1,207 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+
+use crate::utils::solver_types::DayReport;
+
+/// Timings in milliseconds, keyed by solution name (e.g. `day06`) then phase (e.g. `part2`)
+pub type Timings = BTreeMap<String, BTreeMap<String, f32>>;
+
+/// Differences smaller than this are treated as noise, however large they are relative to the baseline
+const NOISE_FLOOR_MS: f32 = 0.1;
+
+/// The timed phases of a report. Phases a solver doesn't measure are `None`.
+/// A solution that skipped part 2 did less work, so its overall times are left out too
+pub fn report_phases(report: &DayReport) -> [(&'static str, Option<f32>); 5] {
+    let did_all_work = !report.skipped_part2;
+
+    [
+        ("parse", Some(report.parse_ms)),
+        ("part1", report.part1_ms),
+        ("part2", report.part2_ms),
+        ("solve", did_all_work.then_some(report.solve_ms)),
+        ("total", did_all_work.then_some(report.total_ms())),
+    ]
+}
+
+/// Add every measured phase of a report to some timings
+pub fn add_report(timings: &mut Timings, name: &str, report: &DayReport) {
+    let phases = timings.entry(name.to_string()).or_default();
+    for (phase, ms) in report_phases(report) {
+        if let Some(ms) = ms {
+            phases.insert(phase.to_string(), ms);
+        }
+    }
+}
+
+/// A phase that got slower than allowed
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub name: String,
+    pub phase: String,
+    pub baseline_ms: f32,
+    pub current_ms: f32,
+    pub slower_by_pct: f32,
+}
+
+/// Timings saved from an earlier run
+pub struct Baseline {
+    path: PathBuf,
+    timings: Timings,
+}
+
+impl Baseline {
+    /// Load a baseline from a JSON file. A missing file is an empty baseline
+    pub fn load(path: &Path) -> Result<Self> {
+        let timings = if path.is_file() {
+            let text = fs::read_to_string(path)
+                .with_context(|| format!("Failed to read baseline {:?}", path))?;
+            serde_json::from_str(&text)
+                .with_context(|| format!("Failed to parse baseline {:?}", path))?
+        } else {
+            Timings::new()
+        };
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            timings,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Find every phase in `current` that is more than `threshold_pct` percent slower than the baseline.
+    /// Phases whose baseline is within the noise floor can't be compared as a percentage, so they're skipped.
+    /// Returns the number of phases compared alongside the regressions
+    pub fn compare(&self, current: &Timings, threshold_pct: f32) -> (usize, Vec<Regression>) {
+        let mut compared = 0;
+        let mut regressions = vec![];
+
+        for (name, phases) in current {
+            let Some(base_phases) = self.timings.get(name) else {
+                continue;
+            };
+            for (phase, current_ms) in phases {
+                let Some(baseline_ms) = base_phases.get(phase) else {
+                    continue;
+                };
+                if *baseline_ms <= NOISE_FLOOR_MS {
+                    continue;
+                }
+                compared += 1;
+
+                let slower_by_pct = (current_ms - baseline_ms) / baseline_ms * 100.0;
+                if slower_by_pct > threshold_pct && current_ms - baseline_ms > NOISE_FLOOR_MS {
+                    regressions.push(Regression {
+                        name: name.clone(),
+                        phase: phase.clone(),
+                        baseline_ms: *baseline_ms,
+                        current_ms: *current_ms,
+                        slower_by_pct,
+                    });
+                }
+            }
+        }
+
+        (compared, regressions)
+    }
+
+    /// Overwrite the baseline with new timings. Solutions and phases that weren't run keep their old timings
+    pub fn update(&mut self, current: Timings) {
+        for (name, phases) in current {
+            self.timings.entry(name).or_default().extend(phases);
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let text = serde_json::to_string_pretty(&self.timings)?;
+        fs::write(&self.path, text)
+            .with_context(|| format!("Failed to write baseline {:?}", self.path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{Baseline, Regression, Timings};
+
+    fn timings(entries: &[(&str, &str, f32)]) -> Timings {
+        let mut t = Timings::new();
+        for (name, phase, ms) in entries {
+            t.entry(name.to_string())
+                .or_default()
+                .insert(phase.to_string(), *ms);
+        }
+        t
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = Baseline {
+            path: PathBuf::new(),
+            timings: timings(&[
+                ("day06", "part2", 100.0),
+                ("day06", "part1", 10.0),
+                ("day12", "parse", 0.2),
+                ("day13", "parse", 0.0),
+            ]),
+        };
+
+        let current = timings(&[
+            // 20% slower
+            ("day06", "part2", 120.0),
+            // 5% slower
+            ("day06", "part1", 10.5),
+            // much slower, but within the noise floor
+            ("day12", "parse", 0.25),
+            // baseline is within the noise floor, so there's no percentage to compare
+            ("day13", "parse", 5.0),
+            // not in the baseline
+            ("day15", "parse", 1.0),
+        ]);
+
+        let expected = vec![Regression {
+            name: "day06".to_string(),
+            phase: "part2".to_string(),
+            baseline_ms: 100.0,
+            current_ms: 120.0,
+            slower_by_pct: 20.0,
+        }];
+
+        assert_eq!((3, expected), baseline.compare(&current, 10.0));
+    }
+
+    #[test]
+    fn test_update_partial() {
+        let mut baseline = Baseline {
+            path: PathBuf::new(),
+            timings: timings(&[
+                ("day06", "parse", 1.0),
+                ("day06", "part1", 10.0),
+                ("day06", "part2", 100.0),
+                ("day12", "parse", 2.0),
+            ]),
+        };
+
+        // a `--part 1` run only times parsing and part 1
+        baseline.update(timings(&[("day06", "parse", 1.5), ("day06", "part1", 12.0)]));
+
+        let expected = timings(&[
+            ("day06", "parse", 1.5),
+            ("day06", "part1", 12.0),
+            ("day06", "part2", 100.0),
+            ("day12", "parse", 2.0),
+        ]);
+        assert_eq!(expected, baseline.timings);
+    }
+}
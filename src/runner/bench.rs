@@ -0,0 +1,236 @@
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+
+use super::{
+    baseline::{report_phases, Timings},
+    report::OutputFormat,
+};
+use crate::utils::solver_types::DayReport;
+
+/// Summary statistics over a set of timing samples, in milliseconds
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Stats {
+    pub min: f32,
+    pub median: f32,
+    pub mean: f32,
+    pub p95: f32,
+    pub std_dev: f32,
+}
+
+impl Stats {
+    /// Calculate the statistics for some samples. Returns `None` if there are no samples
+    pub fn from_samples(samples: &[f32]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(f32::total_cmp);
+        let n = sorted.len();
+
+        let median = if n % 2 == 0 {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0
+        } else {
+            sorted[n / 2]
+        };
+        let mean = sorted.iter().sum::<f32>() / n as f32;
+        let variance = sorted.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / n as f32;
+        // nearest-rank percentile
+        let p95 = sorted[((n as f32 * 0.95).ceil() as usize).clamp(1, n) - 1];
+
+        Some(Self {
+            min: sorted[0],
+            median,
+            mean,
+            p95,
+            std_dev: variance.sqrt(),
+        })
+    }
+}
+
+/// The statistics for a single phase of a solution, e.g. parsing or part 1
+#[derive(Debug, Clone, Serialize)]
+pub struct PhaseStats {
+    pub phase: &'static str,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+/// The results of solving a day many times over
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchReport {
+    pub name: String,
+    pub iterations: usize,
+    pub warmup: usize,
+    pub phases: Vec<PhaseStats>,
+}
+
+impl BenchReport {
+    /// the median time of each phase
+    pub fn timings(&self) -> Timings {
+        let phases = self
+            .phases
+            .iter()
+            .map(|p| (p.phase.to_string(), p.stats.median))
+            .collect();
+        Timings::from([(self.name.clone(), phases)])
+    }
+}
+
+/// Solve a day `warmup` times without recording, then `iterations` times, collecting timings for each phase
+pub fn bench(
+    name: &str,
+    solve: impl Fn(&str) -> Result<DayReport>,
+    input: &str,
+    warmup: usize,
+    iterations: usize,
+) -> Result<BenchReport> {
+    if iterations == 0 {
+        return Err(anyhow!("Must benchmark at least one iteration"));
+    }
+
+    for _ in 0..warmup {
+        solve(input)?;
+    }
+
+    let mut samples: [(&'static str, Vec<f32>); 5] = [
+        ("parse", vec![]),
+        ("part1", vec![]),
+        ("part2", vec![]),
+        ("solve", vec![]),
+        ("total", vec![]),
+    ];
+
+    for _ in 0..iterations {
+        let report = solve(input)?;
+        for ((_, s), (_, p)) in samples.iter_mut().zip(report_phases(&report)) {
+            s.extend(p);
+        }
+    }
+
+    Ok(BenchReport {
+        name: name.to_string(),
+        iterations,
+        warmup,
+        phases: samples
+            .into_iter()
+            .filter_map(|(phase, s)| {
+                Stats::from_samples(&s).map(|stats| PhaseStats { phase, stats })
+            })
+            .collect(),
+    })
+}
+
+/// Print a benchmark in the requested format
+pub fn print_bench(format: OutputFormat, report: &BenchReport) {
+    match format {
+        OutputFormat::Text => {
+            println!(
+                "Benchmarked {} over {} iterations ({} warmup):\n",
+                report.name, report.iterations, report.warmup
+            );
+            println!("phase\tmin\t\tmedian\t\tmean\t\tp95\t\tstd dev");
+            for PhaseStats { phase, stats } in &report.phases {
+                println!(
+                    "{}\t{:.4}ms\t{:.4}ms\t{:.4}ms\t{:.4}ms\t{:.4}ms",
+                    phase, stats.min, stats.median, stats.mean, stats.p95, stats.std_dev
+                );
+            }
+        }
+        OutputFormat::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(report).expect("benchmarks are always serializable")
+        ),
+        OutputFormat::Csv => {
+            println!("name,phase,iterations,min_ms,median_ms,mean_ms,p95_ms,std_dev_ms");
+            for PhaseStats { phase, stats } in &report.phases {
+                println!(
+                    "{},{},{},{},{},{},{},{}",
+                    report.name,
+                    phase,
+                    report.iterations,
+                    stats.min,
+                    stats.median,
+                    stats.mean,
+                    stats.p95,
+                    stats.std_dev
+                );
+            }
+        }
+        OutputFormat::Markdown => {
+            println!("| Phase | Min | Median | Mean | p95 | Std dev |");
+            println!("|-------|----:|-------:|-----:|----:|--------:|");
+            for PhaseStats { phase, stats } in &report.phases {
+                println!(
+                    "| {} | {:.3}ms | {:.3}ms | {:.3}ms | {:.3}ms | {:.3}ms |",
+                    phase, stats.min, stats.median, stats.mean, stats.p95, stats.std_dev
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bench, Stats};
+    use crate::{
+        solutions::templates::{
+            linear_template::ExampleSolutionLinear,
+            simultaneous_template::ExampleSolutionSimultaneous,
+        },
+        utils::solver_types::{solve_linear, solve_simultaneous, Parts, SolveOptions},
+    };
+
+    #[test]
+    fn test_stats() {
+        let samples = [4.0, 1.0, 3.0, 2.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+
+        let expected = Stats {
+            min: 1.0,
+            median: 5.5,
+            mean: 5.5,
+            p95: 10.0,
+            std_dev: 8.25_f32.sqrt(),
+        };
+
+        assert_eq!(Some(expected), Stats::from_samples(&samples));
+        assert_eq!(None, Stats::from_samples(&[]));
+    }
+
+    #[test]
+    fn test_bench_phases() {
+        let linear = bench(
+            "linear",
+            |input| {
+                solve_linear::<ExampleSolutionLinear, _, _, _>(
+                    input,
+                    &SolveOptions::new(Parts::Both),
+                    &mut |_| (),
+                )
+            },
+            "[1,2,3]",
+            1,
+            3,
+        )
+        .unwrap();
+        let phases: Vec<_> = linear.phases.iter().map(|p| p.phase).collect();
+        assert_eq!(vec!["parse", "part1", "part2", "solve", "total"], phases);
+
+        let simultaneous = bench(
+            "simultaneous",
+            |input| {
+                solve_simultaneous::<ExampleSolutionSimultaneous, _, _, _>(
+                    input,
+                    &SolveOptions::new(Parts::Both),
+                    &mut |_| (),
+                )
+            },
+            "[6,5,4,2,3,5,8]",
+            0,
+            3,
+        )
+        .unwrap();
+        let phases: Vec<_> = simultaneous.phases.iter().map(|p| p.phase).collect();
+        assert_eq!(vec!["parse", "part1", "part2", "solve", "total"], phases);
+    }
+}
@@ -0,0 +1,150 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use super::verify::Expected;
+use crate::utils::solver_types::{Parts, RawParams, SolveOptions};
+
+/// A worked example from a puzzle's description.
+/// Examples are stored by year as `<dir>/<year>/dayNN_K.txt`, with their answers under `[dayNN_K]` in `<dir>/<year>/answers.toml`
+/// and any parameters that differ from the real puzzle under `[dayNN_K.params]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub expected: Expected,
+    pub params: RawParams,
+}
+
+/// An example's entry in `answers.toml`
+#[derive(Debug, Default, Deserialize)]
+struct Entry {
+    #[serde(flatten)]
+    expected: Expected,
+    #[serde(default)]
+    params: RawParams,
+}
+
+impl Example {
+    /// the parts this example has answers for. Some examples only cover one part,
+    /// and the other might not even finish on them (e.g. day 17's first example has no part 2)
+    pub fn parts(&self) -> Parts {
+        match (&self.expected.part1, &self.expected.part2) {
+            (Some(_), None) => Parts::Part1,
+            (None, Some(_)) => Parts::Part2,
+            _ => Parts::Both,
+        }
+    }
+
+    /// solve the parts this example has answers for, with its parameters
+    pub fn options(&self) -> SolveOptions {
+        SolveOptions {
+            parts: self.parts(),
+            params: self.params.clone(),
+            ..SolveOptions::default()
+        }
+    }
+}
+
+/// where the examples live when running tests, whatever the working directory
+pub fn test_examples_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("examples")
+}
+
+/// Load every example for a day of `year`, in order. A year without a directory of examples has none
+pub fn load_examples(dir: &Path, year: u16, day: usize) -> Result<Vec<Example>> {
+    let dir = &dir.join(year.to_string());
+    if !dir.is_dir() {
+        return Ok(vec![]);
+    }
+
+    let answers_path = dir.join("answers.toml");
+    let text = fs::read_to_string(&answers_path)
+        .with_context(|| format!("Failed to read {:?}", answers_path))?;
+    let mut entries: BTreeMap<String, Entry> =
+        toml::from_str(&text).with_context(|| format!("Failed to parse {:?}", answers_path))?;
+
+    let prefix = format!("day{:02}_", day);
+
+    let mut numbered = vec![];
+    for entry in fs::read_dir(dir).with_context(|| format!("Failed to read {:?}", dir))? {
+        let path = entry?.path();
+        let Some(name) = path.file_stem().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        let Some(Ok(number)) = name.strip_prefix(&prefix).map(str::parse::<usize>) else {
+            continue;
+        };
+        if path.extension().is_some_and(|e| e == "txt") {
+            numbered.push((number, name.to_string(), path.clone()));
+        }
+    }
+    numbered.sort();
+
+    numbered
+        .into_iter()
+        .map(|(_, name, path)| {
+            let input = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read example {:?}", path))?;
+            let entry = entries.remove(&name).unwrap_or_default();
+            Ok(Example {
+                name,
+                input,
+                expected: entry.expected,
+                params: entry.params,
+            })
+        })
+        .collect()
+}
+
+/// Run one of a day's examples and panic if it doesn't match its answers. Used by each day's tests
+#[cfg(test)]
+pub fn assert_example(year: u16, day: usize, number: usize) {
+    use super::verify::{check, Status};
+    use crate::solutions::REGISTRY;
+
+    let name = format!("day{:02}_{}", day, number);
+    let example = load_examples(&test_examples_dir(), year, day)
+        .unwrap()
+        .into_iter()
+        .find(|e| e.name == name)
+        .unwrap_or_else(|| panic!("no example {}", name));
+
+    let solution = REGISTRY.get(year, day).expect("day should be registered");
+    let result = solution.solve(&example.input, &example.options());
+    let verdict = check(&name, Some(&example.expected), result);
+
+    assert_eq!(Status::Pass, verdict.status, "{:?}", verdict);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{load_examples, test_examples_dir};
+    use crate::utils::solver_types::Parts;
+
+    #[test]
+    fn test_load_examples() {
+        let examples = load_examples(&test_examples_dir(), 2024, 17).unwrap();
+
+        let names: Vec<_> = examples.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(vec!["day17_1", "day17_2"], names);
+        assert_eq!(Parts::Part1, examples[0].parts());
+        assert_eq!(Parts::Both, examples[1].parts());
+        assert_eq!(Some("117440".to_string()), examples[1].expected.part2);
+
+        let day18 = load_examples(&test_examples_dir(), 2024, 18).unwrap();
+        assert_eq!(Some(&toml::Value::Integer(6)), day18[0].params.get("size"));
+
+        assert!(load_examples(&test_examples_dir(), 2024, 99)
+            .unwrap()
+            .is_empty());
+        assert!(load_examples(&test_examples_dir(), 1999, 1)
+            .unwrap()
+            .is_empty());
+    }
+}
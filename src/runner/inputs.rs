@@ -0,0 +1,230 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use sha2::{Digest, Sha256};
+
+/// Where to find a year's puzzle inputs: either straight inside the year's directory under the inputs directory,
+/// or in a profile's subdirectory of it when several people's inputs are kept side by side
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct InputProfile {
+    dir: PathBuf,
+    year: u16,
+    profile: Option<String>,
+}
+
+impl InputProfile {
+    pub fn new(inputs_dir: &Path, year: u16, profile: Option<&str>) -> Self {
+        let year_dir = inputs_dir.join(year.to_string());
+        Self {
+            dir: match profile {
+                Some(p) => year_dir.join(p),
+                None => year_dir,
+            },
+            year,
+            profile: profile.map(str::to_string),
+        }
+    }
+
+    /// one profile for each name given, or just the year's directory if there are none
+    pub fn all(inputs_dir: &Path, year: u16, profiles: &[String]) -> Vec<Self> {
+        if profiles.is_empty() {
+            vec![Self::new(inputs_dir, year, None)]
+        } else {
+            profiles
+                .iter()
+                .map(|p| Self::new(inputs_dir, year, Some(p)))
+                .collect()
+        }
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn profile(&self) -> Option<&str> {
+        self.profile.as_deref()
+    }
+
+    /// the name to report a day under, e.g. `day01`, or `alice/day01` for a profile
+    pub fn name(&self, day: usize) -> String {
+        match &self.profile {
+            Some(p) => format!("{}/day{:02}", p, day),
+            None => format!("day{:02}", day),
+        }
+    }
+
+    /// e.g. `inputs/2024/alice/input_01.txt`
+    pub fn day_path(&self, day: usize) -> PathBuf {
+        self.dir.join(format!("input_{:02}.txt", day))
+    }
+
+    /// the recorded answers for this profile's inputs
+    pub fn answers_path(&self) -> PathBuf {
+        self.dir.join("answers.toml")
+    }
+
+    /// the recorded checksums of this profile's inputs
+    pub fn manifest_path(&self) -> PathBuf {
+        self.dir.join("manifest.toml")
+    }
+}
+
+/// The hex SHA-256 of an input
+pub fn checksum(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Anything about an input's line endings that tends to trip up loaders.
+/// Inputs are expected to look like they were downloaded: `\n` line endings and exactly one trailing newline
+pub fn lint(input: &str) -> Vec<String> {
+    let mut issues = vec![];
+
+    if input.contains("\r\n") {
+        issues.push("has CRLF line endings".to_string());
+    }
+
+    let trailing = input.len() - input.trim_end_matches(['\r', '\n']).len();
+    let newlines = input[input.len() - trailing..].matches('\n').count();
+    match newlines {
+        0 if !input.is_empty() => issues.push("doesn't end with a newline".to_string()),
+        0 | 1 => (),
+        n => issues.push(format!("ends with {} extra blank lines", n - 1)),
+    }
+
+    issues
+}
+
+/// Checksums of the inputs in a directory, keyed by day (e.g. `day09`), loaded from a TOML file like:
+/// ```toml
+/// day09 = "5f1c..."
+/// ```
+/// Recording them alongside the answers lets us tell when an input has changed since
+pub struct Manifest {
+    path: PathBuf,
+    checksums: BTreeMap<String, String>,
+}
+
+impl Manifest {
+    /// Load a manifest from a TOML file. A missing file is an empty manifest
+    pub fn load(path: &Path) -> Result<Self> {
+        let checksums = if path.is_file() {
+            let text = fs::read_to_string(path)
+                .with_context(|| format!("Failed to read manifest {:?}", path))?;
+            toml::from_str(&text).with_context(|| format!("Failed to parse manifest {:?}", path))?
+        } else {
+            BTreeMap::new()
+        };
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            checksums,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// whether an input differs from the one recorded. An input with no recorded checksum hasn't changed
+    pub fn changed(&self, day: &str, input: &str) -> bool {
+        self.checksums
+            .get(day)
+            .is_some_and(|recorded| *recorded != checksum(input))
+    }
+
+    pub fn record(&mut self, day: &str, input: &str) {
+        self.checksums.insert(day.to_string(), checksum(input));
+    }
+
+    pub fn save(&self) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let text = toml::to_string(&self.checksums)?;
+        fs::write(&self.path, text)
+            .with_context(|| format!("Failed to write manifest {:?}", self.path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use rstest::rstest;
+
+    use super::{checksum, lint, InputProfile, Manifest};
+
+    #[test]
+    fn test_paths() {
+        let default = InputProfile::new(Path::new("inputs"), 2024, None);
+        assert_eq!("day01", default.name(1));
+        assert_eq!(
+            PathBuf::from("inputs/2024/input_01.txt"),
+            default.day_path(1)
+        );
+
+        let alice = InputProfile::new(Path::new("inputs"), 2023, Some("alice"));
+        assert_eq!("alice/day14", alice.name(14));
+        assert_eq!(2023, alice.year());
+        assert_eq!(
+            PathBuf::from("inputs/2023/alice/input_14.txt"),
+            alice.day_path(14)
+        );
+        assert_eq!(
+            PathBuf::from("inputs/2023/alice/answers.toml"),
+            alice.answers_path()
+        );
+    }
+
+    #[test]
+    fn test_all() {
+        let profiles = ["alice".to_string(), "bob".to_string()];
+
+        let names: Vec<_> = InputProfile::all(Path::new("inputs"), 2024, &profiles)
+            .iter()
+            .map(|p| p.name(3))
+            .collect();
+        assert_eq!(vec!["alice/day03", "bob/day03"], names);
+
+        assert_eq!(
+            vec![InputProfile::new(Path::new("inputs"), 2024, None)],
+            InputProfile::all(Path::new("inputs"), 2024, &[])
+        );
+    }
+
+    #[rstest]
+    #[case("2333133121414131402\n", vec![])]
+    #[case("2333133121414131402", vec!["doesn't end with a newline"])]
+    #[case("125 17\n\n\n", vec!["ends with 2 extra blank lines"])]
+    #[case("3   4\r\n4   3\r\n", vec!["has CRLF line endings"])]
+    #[case("3   4\r\n4   3", vec!["has CRLF line endings", "doesn't end with a newline"])]
+    #[case("", vec![])]
+    fn validate_lint(#[case] input: &str, #[case] expected: Vec<&str>) {
+        assert_eq!(expected, lint(input));
+    }
+
+    #[test]
+    fn test_manifest() {
+        let mut manifest = Manifest {
+            path: PathBuf::new(),
+            checksums: Default::default(),
+        };
+        assert!(!manifest.changed("day11", "125 17\n"));
+
+        manifest.record("day11", "125 17\n");
+        assert!(!manifest.changed("day11", "125 17\n"));
+        assert!(manifest.changed("day11", "125 17"));
+
+        assert_eq!(
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+            checksum("")
+        );
+    }
+}
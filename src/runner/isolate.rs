@@ -0,0 +1,220 @@
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Once,
+    },
+    thread,
+    time::Duration,
+};
+
+use anyhow::{anyhow, Context, Result};
+
+use crate::utils::progress::Cancel;
+
+/// Stack size for threads that run solutions. Some solutions recurse deeply, and spawned threads
+/// only get 2MiB by default
+pub const SOLVER_STACK_SIZE: usize = 64 * 1024 * 1024;
+
+thread_local! {
+    /// whether this thread is inside `catch_panic`
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    /// where the last caught panic on this thread happened
+    static LAST_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// Replace the panic hook with one that stays quiet for panics we're about to catch,
+/// noting where they happened. Every other panic goes to the default hook as usual
+fn install_hook() {
+    INSTALL_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.with(Cell::get) {
+                let location = info.location().map(|l| l.to_string());
+                LAST_LOCATION.with(|l| *l.borrow_mut() = location);
+            } else {
+                default_hook(info);
+            }
+        }));
+    });
+}
+
+/// panics carry either a `&str` or a `String`, depending on whether they were formatted
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg.to_string()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+/// Run `f`, turning a panic into an error carrying the panic's message and location
+pub fn catch_panic<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+    install_hook();
+
+    let was_catching = CATCHING.with(|c| c.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|c| c.set(was_catching));
+
+    match result {
+        Ok(res) => res,
+        Err(payload) => {
+            let msg = panic_message(payload.as_ref());
+            match LAST_LOCATION.with(|l| l.borrow_mut().take()) {
+                Some(location) => Err(anyhow!("panicked at {}: {}", location, msg)),
+                None => Err(anyhow!("panicked: {}", msg)),
+            }
+        }
+    }
+}
+
+/// The error for a solution that took longer than it was allowed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimedOut(pub Duration);
+
+impl Display for TimedOut {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "timed out after {:?}", self.0)
+    }
+}
+
+impl std::error::Error for TimedOut {}
+
+/// Run `f` like `catch_panic`, but stop waiting for it after `timeout`, cancel it with `cancel` and return a
+/// `TimedOut` error. Threads can't be killed, so a timed out `f` carries on in the background until it notices
+/// it's been cancelled, or until the process exits
+pub fn run_isolated<T: Send + 'static>(
+    timeout: Option<Duration>,
+    cancel: &Cancel,
+    f: impl FnOnce() -> Result<T> + Send + 'static,
+) -> Result<T> {
+    let Some(timeout) = timeout else {
+        return catch_panic(f);
+    };
+
+    let (tx, rx) = mpsc::channel();
+    thread::Builder::new()
+        .stack_size(SOLVER_STACK_SIZE)
+        .spawn(move || {
+            // nobody is listening if we timed out, which is fine
+            let _ = tx.send(catch_panic(f));
+        })
+        .context("Failed to start solver thread")?;
+
+    match rx.recv_timeout(timeout) {
+        Ok(res) => res,
+        Err(RecvTimeoutError::Timeout) => {
+            cancel.cancel();
+            Err(TimedOut(timeout).into())
+        }
+        Err(RecvTimeoutError::Disconnected) => {
+            Err(anyhow!("solver thread stopped without a result"))
+        }
+    }
+}
+
+/// Parse a duration like `500ms`, `10s`, `1.5m` or `1h`. A bare number is in seconds
+pub fn parse_duration(text: &str) -> Result<Duration> {
+    let text = text.trim();
+    let split = text
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(text.len());
+    let (value, unit) = text.split_at(split);
+
+    let value: f64 = value
+        .parse()
+        .with_context(|| format!("'{}' doesn't start with a number", text))?;
+    let secs = match unit.trim() {
+        "ms" => value / 1000.0,
+        "" | "s" => value,
+        "m" => value * 60.0,
+        "h" => value * 3600.0,
+        unit => return Err(anyhow!("unknown unit '{}', expected ms, s, m or h", unit)),
+    };
+
+    Duration::try_from_secs_f64(secs).with_context(|| format!("'{}' is not a valid duration", text))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use anyhow::anyhow;
+    use rstest::rstest;
+
+    use super::{catch_panic, parse_duration, run_isolated, TimedOut};
+    use crate::utils::progress::Cancel;
+
+    #[test]
+    fn test_catch_panic() {
+        let caught = catch_panic::<()>(|| panic!("No tree found.")).unwrap_err();
+        let msg = caught.to_string();
+        assert!(
+            msg.starts_with("panicked at src/runner/isolate.rs:"),
+            "{}",
+            msg
+        );
+        assert!(msg.ends_with(": No tree found."), "{}", msg);
+
+        let formatted = catch_panic::<()>(|| panic!("Unrecognised value {}", 9)).unwrap_err();
+        assert!(formatted.to_string().ends_with(": Unrecognised value 9"));
+
+        let err = catch_panic::<()>(|| Err(anyhow!("plain error"))).unwrap_err();
+        assert_eq!("plain error", err.to_string());
+
+        assert_eq!(5, catch_panic(|| Ok(5)).unwrap());
+    }
+
+    #[test]
+    fn test_timeout() {
+        let cancel = Cancel::default();
+        let err = run_isolated::<()>(Some(Duration::from_millis(20)), &cancel, || {
+            thread::sleep(Duration::from_secs(5));
+            Ok(())
+        })
+        .unwrap_err();
+        assert_eq!(
+            Some(&TimedOut(Duration::from_millis(20))),
+            err.downcast_ref::<TimedOut>()
+        );
+        assert!(cancel.is_cancelled());
+
+        let cancel = Cancel::default();
+        let in_time = run_isolated(Some(Duration::from_secs(5)), &cancel, || Ok(5));
+        assert_eq!(5, in_time.unwrap());
+        assert!(!cancel.is_cancelled());
+
+        let panicked = run_isolated::<()>(Some(Duration::from_secs(5)), &cancel, || {
+            panic!("Invalid operand")
+        });
+        assert!(panicked
+            .unwrap_err()
+            .to_string()
+            .ends_with(": Invalid operand"));
+    }
+
+    #[rstest]
+    #[case("500ms", Duration::from_millis(500))]
+    #[case("10s", Duration::from_secs(10))]
+    #[case("10", Duration::from_secs(10))]
+    #[case("1.5m", Duration::from_secs(90))]
+    #[case("1h", Duration::from_secs(3600))]
+    fn validate_parse_duration(#[case] input: &str, #[case] expected: Duration) {
+        assert_eq!(expected, parse_duration(input).unwrap());
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("ten seconds")]
+    #[case("10 fortnights")]
+    fn validate_parse_duration_errors(#[case] input: &str) {
+        assert!(parse_duration(input).is_err());
+    }
+}
@@ -0,0 +1,10 @@
+pub mod baseline;
+pub mod bench;
+pub mod examples;
+pub mod inputs;
+pub mod isolate;
+pub mod pool;
+pub mod report;
+pub mod scaffold;
+pub mod select;
+pub mod verify;
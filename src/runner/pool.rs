@@ -0,0 +1,127 @@
+use std::{
+    collections::BTreeMap,
+    num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+use anyhow::Result;
+
+use super::isolate::SOLVER_STACK_SIZE;
+
+/// Resolve a requested job count, where 0 means one job per available core
+pub fn job_count(jobs: usize) -> usize {
+    match jobs {
+        0 => thread::available_parallelism()
+            .map(NonZeroUsize::get)
+            .unwrap_or(1),
+        n => n,
+    }
+}
+
+/// Run `task` for every index in `0..count` across `jobs` worker threads.
+/// Results are handed to `on_result` in index order: a result is held back until every earlier index has been handed over.
+/// If `on_result` fails, no new tasks are started and the error is returned once running tasks finish.
+pub fn run_in_order<T, F, R>(count: usize, jobs: usize, task: F, mut on_result: R) -> Result<()>
+where
+    T: Send,
+    F: Fn(usize) -> T + Sync,
+    R: FnMut(usize, T) -> Result<()>,
+{
+    let next = AtomicUsize::new(0);
+    let stop = AtomicBool::new(false);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|s| {
+        for _ in 0..jobs.clamp(1, count.max(1)) {
+            let tx = tx.clone();
+            let (next, stop, task) = (&next, &stop, &task);
+            thread::Builder::new()
+                .stack_size(SOLVER_STACK_SIZE)
+                .spawn_scoped(s, move || loop {
+                    let i = next.fetch_add(1, Ordering::SeqCst);
+                    if i >= count || stop.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    if tx.send((i, task(i))).is_err() {
+                        break;
+                    }
+                })
+                .expect("failed to start worker thread");
+        }
+        // workers hold the remaining senders, so the receiver closes once they're all done
+        drop(tx);
+
+        let mut pending = BTreeMap::new();
+        let mut due = 0;
+
+        for (i, res) in rx {
+            pending.insert(i, res);
+            while let Some(res) = pending.remove(&due) {
+                if let Err(err) = on_result(due, res) {
+                    stop.store(true, Ordering::SeqCst);
+                    return Err(err);
+                }
+                due += 1;
+            }
+        }
+
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use anyhow::anyhow;
+
+    use super::run_in_order;
+
+    #[test]
+    fn test_results_in_order() {
+        let mut seen = vec![];
+
+        run_in_order(
+            8,
+            4,
+            |i| {
+                // make early indexes finish last
+                thread::sleep(Duration::from_millis((8 - i as u64) * 5));
+                i * 10
+            },
+            |i, v| {
+                seen.push((i, v));
+                Ok(())
+            },
+        )
+        .unwrap();
+
+        assert_eq!((0..8).map(|i| (i, i * 10)).collect::<Vec<_>>(), seen);
+    }
+
+    #[test]
+    fn test_stops_on_error() {
+        let mut seen = vec![];
+
+        let result = run_in_order(
+            100,
+            1,
+            |i| i,
+            |i, _| {
+                seen.push(i);
+                if i == 2 {
+                    Err(anyhow!("stop"))
+                } else {
+                    Ok(())
+                }
+            },
+        );
+
+        assert!(result.is_err());
+        assert_eq!(vec![0, 1, 2], seen);
+    }
+}
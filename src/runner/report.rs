@@ -0,0 +1,345 @@
+use anyhow::Error;
+use clap::ValueEnum;
+use serde::Serialize;
+
+use super::isolate::TimedOut;
+use crate::utils::solver_types::{DayReport, Solved, SolverKind};
+
+/// How solution reports are written to stdout
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// human-readable, tab-aligned text
+    #[default]
+    Text,
+    /// a JSON object with one record per solution and the overall times
+    Json,
+    /// comma-separated values with a header row
+    Csv,
+    /// a Markdown table of timings
+    Markdown,
+}
+
+/// A single row of output: a named report
+#[derive(Debug, Serialize)]
+struct Record {
+    name: String,
+    kind: SolverKind,
+    part1: Option<String>,
+    part2: Option<String>,
+    parse_ms: f32,
+    part1_ms: Option<f32>,
+    part2_ms: Option<f32>,
+    solve_ms: f32,
+    total_ms: f32,
+}
+
+impl Record {
+    fn new(name: &str, report: DayReport) -> Self {
+        Self {
+            name: name.to_string(),
+            kind: report.kind,
+            parse_ms: report.parse_ms,
+            part1_ms: report.part1_ms,
+            part2_ms: report.part2_ms,
+            solve_ms: report.solve_ms,
+            total_ms: report.total_ms(),
+            part1: report.part1,
+            part2: report.part2,
+        }
+    }
+}
+
+/// A solution that couldn't be run, and why
+#[derive(Debug, Serialize)]
+struct Failure {
+    name: String,
+    error: String,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    timed_out: bool,
+}
+
+const CSV_HEADER: &str = "name,kind,part1,part2,parse_ms,part1_ms,part2_ms,solve_ms,total_ms";
+
+/// Collects reports and writes them in the requested format.
+/// Text is printed as soon as a report arrives, or as each part is solved if it's streamed.
+/// Every other format is printed by `finish`
+pub struct Reporter {
+    format: OutputFormat,
+    streaming: bool,
+    records: Vec<Record>,
+    failures: Vec<Failure>,
+    wall_ms: Option<f32>,
+}
+
+/// Everything written out by the JSON format
+#[derive(Debug, Serialize)]
+struct JsonOutput<'a> {
+    records: &'a [Record],
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    failures: &'a [Failure],
+    total_ms: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    wall_ms: Option<f32>,
+}
+
+impl Reporter {
+    pub fn new(format: OutputFormat) -> Self {
+        Self {
+            format,
+            streaming: false,
+            records: vec![],
+            failures: vec![],
+            wall_ms: None,
+        }
+    }
+
+    pub fn is_text(&self) -> bool {
+        self.format == OutputFormat::Text
+    }
+
+    /// What to pass a solution to show each step as soon as it's done. Only text is streamed,
+    /// and the reports that follow leave out what's already been shown
+    pub fn stream(&mut self) -> fn(&Solved) {
+        if self.is_text() {
+            self.streaming = true;
+            print_solved
+        } else {
+            |_| ()
+        }
+    }
+
+    /// add a report under the given name, e.g. `day01`
+    pub fn report(&mut self, name: &str, report: DayReport) {
+        match (self.is_text(), self.streaming) {
+            (true, true) => print_summary(&report),
+            (true, false) => print_text(&report),
+            _ => (),
+        }
+        self.records.push(Record::new(name, report));
+    }
+
+    /// add a solution that failed. The full error chain goes to stderr straight away, whatever the format
+    pub fn fail(&mut self, name: &str, err: &Error) {
+        let timed_out = err.downcast_ref::<TimedOut>().is_some();
+        if timed_out {
+            eprintln!("{} {}", name, err);
+        } else {
+            eprintln!("{} failed: {:?}", name, err);
+        }
+        self.failures.push(Failure {
+            name: name.to_string(),
+            error: format!("{:#}", err),
+            timed_out,
+        });
+    }
+
+    /// the names of every solution that failed so far
+    pub fn failures(&self) -> Vec<String> {
+        self.failures.iter().map(|f| f.name.clone()).collect()
+    }
+
+    /// the summed time of every report so far
+    pub fn total_ms(&self) -> f32 {
+        // summing no f32s gives -0.0, which would print as -0ms
+        self.records.iter().fold(0.0, |total, r| total + r.total_ms)
+    }
+
+    /// record how long the whole run took, which is less than `total_ms` when days run in parallel
+    pub fn set_wall_ms(&mut self, wall_ms: f32) {
+        self.wall_ms = Some(wall_ms);
+    }
+
+    /// write out everything collected so far. Text has already been printed
+    pub fn finish(self) {
+        let total_ms = self.total_ms();
+
+        match self.format {
+            OutputFormat::Text => (),
+            OutputFormat::Json => println!(
+                "{}",
+                to_json(&JsonOutput {
+                    records: &self.records,
+                    failures: &self.failures,
+                    total_ms,
+                    wall_ms: self.wall_ms,
+                })
+            ),
+            OutputFormat::Csv => print!("{}", to_csv(&self.records)),
+            OutputFormat::Markdown => {
+                print!("{}", to_markdown(&self.records, total_ms, self.wall_ms))
+            }
+        }
+    }
+}
+
+/// Print a step of solving a day as human-readable, tab-aligned text
+fn print_solved(solved: &Solved) {
+    match solved {
+        Solved::Input { ms } => println!("Parsed input in:\t{}ms", ms),
+        Solved::Part { part, answer, ms } => {
+            if let Some(answer) = answer {
+                println!("Part {} Solution: \t{}", part, answer);
+            }
+            println!("Part {} solved in:\t{}ms", part, ms);
+        }
+    }
+}
+
+/// Print the overall times of a day whose steps have already been printed
+fn print_summary(report: &DayReport) {
+    if report.kind == SolverKind::Simultaneous {
+        println!("Solved in:\t\t{}ms", report.solve_ms);
+    }
+    println!("Overall time:\t\t{}ms", report.total_ms());
+}
+
+/// Print a day's answers and timings as human-readable, tab-aligned text. Parts that weren't asked for are left out
+fn print_text(report: &DayReport) {
+    print_solved(&Solved::Input {
+        ms: report.parse_ms,
+    });
+
+    let parts = [
+        (1, &report.part1, report.part1_ms),
+        (2, &report.part2, report.part2_ms),
+    ];
+    for (part, answer, ms) in parts {
+        match ms {
+            Some(ms) => print_solved(&Solved::Part {
+                part,
+                answer: answer.clone(),
+                ms,
+            }),
+            None => {
+                if let Some(answer) = answer {
+                    println!("Part {} Solution: \t{}", part, answer);
+                }
+            }
+        }
+    }
+
+    print_summary(report);
+}
+
+fn to_json(output: &JsonOutput) -> String {
+    serde_json::to_string_pretty(output).expect("records are always serializable")
+}
+
+/// quote a csv field if it contains anything that would break the row (e.g. day 17's comma-separated answer)
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn csv_opt(v: Option<f32>) -> String {
+    v.map(|v| v.to_string()).unwrap_or_default()
+}
+
+fn to_csv(records: &[Record]) -> String {
+    let mut out = format!("{}\n", CSV_HEADER);
+
+    for r in records {
+        out.push_str(&format!(
+            "{},{},{},{},{},{},{},{},{}\n",
+            csv_field(&r.name),
+            r.kind,
+            csv_field(r.part1.as_deref().unwrap_or_default()),
+            csv_field(r.part2.as_deref().unwrap_or_default()),
+            r.parse_ms,
+            csv_opt(r.part1_ms),
+            csv_opt(r.part2_ms),
+            r.solve_ms,
+            r.total_ms
+        ));
+    }
+
+    out
+}
+
+fn md_ms(v: Option<f32>) -> String {
+    v.map(|v| format!("{:.3}ms", v)).unwrap_or("-".to_string())
+}
+
+/// a timing table, ready to paste into a README. Answers are left out on purpose
+fn to_markdown(records: &[Record], total_ms: f32, wall_ms: Option<f32>) -> String {
+    let mut out = String::from(
+        "| Name | Kind | Parse | Part 1 | Part 2 | Solve | Total |\n\
+         |------|------|------:|-------:|-------:|------:|------:|\n",
+    );
+
+    for r in records {
+        out.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} | {} |\n",
+            r.name,
+            r.kind,
+            md_ms(Some(r.parse_ms)),
+            md_ms(r.part1_ms),
+            md_ms(r.part2_ms),
+            md_ms(Some(r.solve_ms)),
+            md_ms(Some(r.total_ms)),
+        ));
+    }
+
+    if records.len() > 1 {
+        out.push_str(&format!(
+            "| **Total** | | | | | | **{}** |\n",
+            md_ms(Some(total_ms))
+        ));
+    }
+
+    if let Some(wall_ms) = wall_ms {
+        out.push_str(&format!(
+            "| **Wall clock** | | | | | | **{}** |\n",
+            md_ms(Some(wall_ms))
+        ));
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_csv, to_markdown, OutputFormat, Record, Reporter, CSV_HEADER};
+    use crate::utils::solver_types::{DayReport, SolverKind};
+
+    fn record(part1: &str) -> Record {
+        Record::new(
+            "day17",
+            DayReport {
+                kind: SolverKind::Linear,
+                part1: Some(part1.to_string()),
+                part2: Some("117440".to_string()),
+                parse_ms: 1.0,
+                part1_ms: Some(2.0),
+                part2_ms: Some(3.0),
+                solve_ms: 5.0,
+                skipped_part2: false,
+            },
+        )
+    }
+
+    #[test]
+    fn test_csv_quotes_commas() {
+        let expected = format!("{}\nday17,linear,\"4,6,3\",117440,1,2,3,5,6\n", CSV_HEADER);
+
+        assert_eq!(expected, to_csv(&[record("4,6,3")]));
+    }
+
+    #[test]
+    fn test_markdown_total_row() {
+        let table = to_markdown(&[record("1"), record("2")], 12.0, None);
+
+        assert_eq!(5, table.lines().count());
+        assert!(table.ends_with("| **Total** | | | | | | **12.000ms** |\n"));
+    }
+
+    #[test]
+    fn test_total_without_reports() {
+        let reporter = Reporter::new(OutputFormat::Text);
+
+        assert_eq!("0", reporter.total_ms().to_string());
+    }
+}
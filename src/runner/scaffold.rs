@@ -0,0 +1,323 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use anyhow::{anyhow, Context, Result};
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+/// Where each year's module of solutions lives, e.g. `src/solutions/y2024`
+const SOLUTIONS_DIR: &str = "src/solutions";
+
+const MOD_TEMPLATE: &str = include_str!("../../templates/mod_template.template");
+
+/// The templates a day can start from, by name
+const TEMPLATES: [(&str, &str); 4] = [
+    (
+        "linear",
+        include_str!("../../templates/linear_template.template"),
+    ),
+    (
+        "simultaneous",
+        include_str!("../../templates/simultaneous_template.template"),
+    ),
+    // a linear solution that loads its input with `load_2d_grid`
+    (
+        "grid",
+        include_str!("../../templates/grid_template.template"),
+    ),
+    // a linear solution that parses its input into a program and runs it
+    ("vm", include_str!("../../templates/vm_template.template")),
+];
+
+const ANSWERS_HEADER: &str = "\
+# Expected answers for the worked examples in each puzzle's description, keyed by example file.
+# Leave out a part that an example doesn't cover and it won't be run.
+# Anything that differs from the real puzzle, like a grid size, goes in the example's params table.
+";
+
+lazy_static! {
+    static ref MOD_DAY: Regex = Regex::new(r"^mod day(\d+);$").unwrap();
+    static ref REGISTER_DAY: Regex =
+        Regex::new(r"^\s*registry\.register\(day(\d+)::solution\(\)\);$").unwrap();
+    static ref MOD_YEAR: Regex = Regex::new(r"^mod y(\d+);$").unwrap();
+    static ref REGISTER_YEAR: Regex =
+        Regex::new(r"^\s*y(\d+)::register\(&mut registry\);$").unwrap();
+}
+
+/// Load the user's own templates from `<dir>/<name>.template`, by name. A missing directory has none
+pub fn load_user_templates(dir: &Path) -> Result<BTreeMap<String, String>> {
+    let mut templates = BTreeMap::new();
+    if !dir.is_dir() {
+        return Ok(templates);
+    }
+
+    for entry in fs::read_dir(dir).with_context(|| format!("Failed to read {:?}", dir))? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|e| e == "template") {
+            if let Some(name) = path.file_stem().and_then(|n| n.to_str()) {
+                let text = fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read template {:?}", path))?;
+                templates.insert(name.to_string(), text);
+            }
+        }
+    }
+
+    Ok(templates)
+}
+
+/// Pick a template by name. A user's template of the same name is used instead of a built-in one
+pub fn pick_template(name: &str, user_templates: &BTreeMap<String, String>) -> Result<String> {
+    if let Some(template) = user_templates.get(name) {
+        return Ok(template.clone());
+    }
+    if let Some((_, template)) = TEMPLATES.iter().find(|(n, _)| *n == name) {
+        return Ok(template.to_string());
+    }
+
+    let names = TEMPLATES
+        .iter()
+        .map(|(n, _)| *n)
+        .chain(user_templates.keys().map(String::as_str))
+        .unique()
+        .join(", ");
+    Err(anyhow!(
+        "No template called '{}'. Try one of: {}",
+        name,
+        names
+    ))
+}
+
+/// Fill in a template's placeholders for a day
+pub fn render(template: &str, year: u16, day: usize) -> String {
+    template
+        .replace("{{ day_value }}", &format!("{:02}", day))
+        .replace("{{ day_number }}", &day.to_string())
+        .replace("{{ year }}", &year.to_string())
+        // the last day of a year only has one part
+        .replace("{{ parts }}", if day == 25 { "1" } else { "2" })
+}
+
+/// Add `line` among the lines matching `pattern`, keeping them ordered by the number it captures.
+/// The rest of `text` is left as it is, and a line that's already there isn't added again
+pub fn insert_ordered(text: &str, pattern: &Regex, line: &str, number: usize) -> Result<String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    if lines.iter().any(|l| l.trim() == line.trim()) {
+        return Ok(text.to_string());
+    }
+
+    let matching: Vec<(usize, usize)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| {
+            let n = pattern.captures(l)?[1].parse().ok()?;
+            Some((i, n))
+        })
+        .collect();
+
+    let at = match matching.iter().rfind(|(_, n)| *n < number) {
+        Some((i, _)) => i + 1,
+        None => {
+            matching
+                .first()
+                .ok_or_else(|| anyhow!("Couldn't find where to add `{}`", line.trim()))?
+                .0
+        }
+    };
+    lines.insert(at, line);
+
+    let mut out = lines.join("\n");
+    if text.ends_with('\n') {
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+/// Start a new day: create its solution file from `template` and register it in its year's module,
+/// creating and registering the year's module too if this is its first day. Empty placeholders are made for the
+/// day's input and first example. Paths are relative to the root of the repository
+pub fn new_day(
+    year: u16,
+    day: usize,
+    template: &str,
+    inputs_dir: &Path,
+    examples_dir: &Path,
+) -> Result<()> {
+    let solutions_dir = Path::new(SOLUTIONS_DIR);
+    let solutions_mod = solutions_dir.join("mod.rs");
+    if !solutions_mod.is_file() {
+        return Err(anyhow!(
+            "Couldn't find {:?}. Run this from the root of the repository",
+            solutions_mod
+        ));
+    }
+
+    let year_dir = solutions_dir.join(format!("y{}", year));
+    let day_path = year_dir.join(format!("day{:02}.rs", day));
+    if day_path.exists() {
+        return Err(anyhow!("{:?} already exists", day_path));
+    }
+
+    // Work out every registration before writing anything, then write the day's file before registering it.
+    // If registering fails, the day's file is removed again so the crate still builds
+    let year_mod = year_dir.join("mod.rs");
+    let (solutions_text, year_text) = if year_mod.is_file() {
+        let text = edited(&year_mod, |text| {
+            let text = insert_ordered(text, &MOD_DAY, &format!("mod day{:02};", day), day)?;
+            insert_ordered(
+                &text,
+                &REGISTER_DAY,
+                &format!("    registry.register(day{:02}::solution());", day),
+                day,
+            )
+        })?;
+
+        (None, text)
+    } else {
+        let year_number = year.into();
+        let solutions_text = edited(&solutions_mod, |text| {
+            let text = insert_ordered(text, &MOD_YEAR, &format!("mod y{};", year), year_number)?;
+            insert_ordered(
+                &text,
+                &REGISTER_YEAR,
+                &format!("        y{}::register(&mut registry);", year),
+                year_number,
+            )
+        })?;
+        let text = MOD_TEMPLATE
+            .replace("{{ mods }}", &format!("mod day{:02};", day))
+            .replace(
+                "{{ solutions }}",
+                &format!("    registry.register(day{:02}::solution());", day),
+            );
+
+        (Some(solutions_text), text)
+    };
+
+    create_file(&day_path, &render(template, year, day))?;
+
+    // a new year's module isn't built until the solutions module names it, so that goes last
+    let registered = match &solutions_text {
+        None => update_file(&year_mod, &year_text),
+        Some(solutions_text) => create_file(&year_mod, &year_text)
+            .and_then(|_| update_file(&solutions_mod, solutions_text)),
+    };
+    if let Err(e) = registered {
+        if solutions_text.is_some() {
+            let _ = fs::remove_file(&year_mod);
+        }
+        let _ = fs::remove_file(&day_path);
+        return Err(e);
+    }
+
+    let inputs_dir = inputs_dir.join(year.to_string());
+    create_placeholder(&inputs_dir.join(format!("input_{:02}.txt", day)), "")?;
+
+    let examples_dir = examples_dir.join(year.to_string());
+    create_placeholder(&examples_dir.join(format!("day{:02}_1.txt", day)), "")?;
+    create_placeholder(&examples_dir.join("answers.toml"), ANSWERS_HEADER)
+}
+
+fn create_file(path: &Path, contents: &str) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, contents).with_context(|| format!("Failed to write {:?}", path))?;
+    println!("Created {}", path.display());
+    Ok(())
+}
+
+/// create a file unless it's already there
+fn create_placeholder(path: &Path, contents: &str) -> Result<()> {
+    if path.exists() {
+        return Ok(());
+    }
+    create_file(path, contents)
+}
+
+/// the contents of the file at `path` after `edit`, without writing them
+fn edited(path: &Path, edit: impl FnOnce(&str) -> Result<String>) -> Result<String> {
+    let text = fs::read_to_string(path).with_context(|| format!("Failed to read {:?}", path))?;
+    edit(&text).with_context(|| format!("Failed to update {:?}", path))
+}
+
+fn update_file(path: &Path, contents: &str) -> Result<()> {
+    fs::write(path, contents).with_context(|| format!("Failed to write {:?}", path))?;
+    println!("Updated {}", path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use rstest::rstest;
+
+    use super::{insert_ordered, pick_template, render, MOD_DAY, REGISTER_YEAR, TEMPLATES};
+
+    #[test]
+    fn test_render() {
+        for (name, template) in TEMPLATES {
+            let rendered = render(template, 2024, 7);
+
+            assert!(!rendered.contains("{{"), "{}", name);
+            assert!(rendered.contains("pub struct Day07Solution"), "{}", name);
+            assert!(
+                rendered.contains("assert_example(2024, 7, example)"),
+                "{}",
+                name
+            );
+            assert!(render(template, 2024, 25).contains("parts: 1,"), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_pick_template() {
+        let mut user = BTreeMap::new();
+        user.insert("mine".to_string(), "mine".to_string());
+        user.insert("grid".to_string(), "my grid".to_string());
+
+        assert_eq!("mine", pick_template("mine", &user).unwrap());
+        assert_eq!("my grid", pick_template("grid", &user).unwrap());
+        assert!(pick_template("vm", &user)
+            .unwrap()
+            .contains("struct Machine"));
+
+        let err = pick_template("nope", &user).unwrap_err().to_string();
+        assert!(
+            err.ends_with("linear, simultaneous, grid, vm, mine"),
+            "{}",
+            err
+        );
+    }
+
+    #[rstest]
+    #[case("mod day01;\nmod day03;\n", 2, "mod day01;\nmod day02;\nmod day03;\n")]
+    #[case("mod day02;\nmod day03;\n", 1, "mod day01;\nmod day02;\nmod day03;\n")]
+    #[case(
+        "mod day01;\n\nfn f() {}\n",
+        9,
+        "mod day01;\nmod day09;\n\nfn f() {}\n"
+    )]
+    #[case("mod day01;\nmod day03;\n", 3, "mod day01;\nmod day03;\n")]
+    fn validate_insert_ordered(#[case] text: &str, #[case] day: usize, #[case] expected: &str) {
+        let line = format!("mod day{:02};", day);
+
+        assert_eq!(
+            expected,
+            insert_ordered(text, &MOD_DAY, &line, day).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_insert_keeps_hand_edits() {
+        let text =
+            "        // hand edit\n        y2024::register(&mut registry);\n        registry\n";
+        let line = "        y2023::register(&mut registry);";
+
+        assert_eq!(
+            "        // hand edit\n        y2023::register(&mut registry);\n        y2024::register(&mut registry);\n        registry\n",
+            insert_ordered(text, &REGISTER_YEAR, line, 2023).unwrap()
+        );
+        assert!(insert_ordered("fn f() {}\n", &REGISTER_YEAR, line, 2023).is_err());
+    }
+}
@@ -0,0 +1,115 @@
+use std::{collections::BTreeSet, str::FromStr};
+
+use anyhow::{anyhow, Context, Error, Result};
+
+/// The last day of any year's puzzles
+pub const LAST_DAY: usize = 25;
+
+/// A set of days, written as a comma-separated list of days and inclusive ranges, e.g. `1-5,9,15-20`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DaySet(BTreeSet<usize>);
+
+impl FromStr for DaySet {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        // checked here, before a range is expanded, so a typo like 1-99999999 can't run away
+        let parse_day = |d: &str| {
+            let day = d
+                .trim()
+                .parse::<usize>()
+                .with_context(|| format!("'{}' is not a day", d.trim()))?;
+            if day > LAST_DAY {
+                return Err(anyhow!("there's no day {}, the last is {}", day, LAST_DAY));
+            }
+            Ok(day)
+        };
+
+        let mut days = BTreeSet::new();
+
+        for item in s.split(',') {
+            match item.split_once('-') {
+                Some((from, to)) => {
+                    let (from, to) = (parse_day(from)?, parse_day(to)?);
+                    if from > to {
+                        return Err(anyhow!("range '{}' runs backwards", item.trim()));
+                    }
+                    days.extend(from..=to);
+                }
+                None => {
+                    days.insert(parse_day(item)?);
+                }
+            }
+        }
+
+        Ok(Self(days))
+    }
+}
+
+impl DaySet {
+    /// every one of the given days, e.g. those with a solution
+    pub fn all(days: impl IntoIterator<Item = usize>) -> Self {
+        Self(days.into_iter().collect())
+    }
+
+    /// the days in this set that aren't in `other`
+    pub fn without(&self, other: &DaySet) -> Self {
+        Self(self.0.difference(&other.0).copied().collect())
+    }
+
+    /// check every day is one of the `solved` days
+    pub fn check(&self, solved: &DaySet) -> Result<()> {
+        match self.0.difference(&solved.0).next() {
+            Some(d) => Err(anyhow!("Day '{}' is invalid or not yet solved", d)),
+            None => Ok(()),
+        }
+    }
+
+    /// the days in ascending order
+    pub fn days(&self) -> Vec<usize> {
+        self.0.iter().copied().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::DaySet;
+
+    #[rstest]
+    #[case("9", vec![9])]
+    #[case("1-5,9,15-20", vec![1, 2, 3, 4, 5, 9, 15, 16, 17, 18, 19, 20])]
+    #[case("6, 14, 6", vec![6, 14])]
+    #[case("3-3", vec![3])]
+    fn validate_parse(#[case] input: &str, #[case] expected: Vec<usize>) {
+        assert_eq!(expected, input.parse::<DaySet>().unwrap().days());
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("one")]
+    #[case("5-1")]
+    #[case("1-")]
+    #[case("26")]
+    #[case("1-99999999999")]
+    fn validate_parse_errors(#[case] input: &str) {
+        assert!(input.parse::<DaySet>().is_err());
+    }
+
+    #[test]
+    fn test_select() {
+        let except: DaySet = "6,14".parse().unwrap();
+        let days = DaySet::all(1..=8).without(&except);
+
+        assert_eq!(vec![1, 2, 3, 4, 5, 7, 8], days.days());
+        assert!(days.check(&DaySet::all(1..=8)).is_ok());
+        let solved = DaySet::all(1..=20);
+        assert!("0,3".parse::<DaySet>().unwrap().check(&solved).is_err());
+        assert!("19-21".parse::<DaySet>().unwrap().check(&solved).is_err());
+        // days can be solved out of order
+        let gaps = DaySet::all([1, 2, 25]);
+        assert!("1,25".parse::<DaySet>().unwrap().check(&gaps).is_ok());
+        assert!("3".parse::<DaySet>().unwrap().check(&gaps).is_err());
+    }
+}
@@ -0,0 +1,313 @@
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use super::{isolate::TimedOut, report::OutputFormat};
+use crate::utils::solver_types::DayReport;
+
+/// The known-correct answers for a puzzle input. Either part can be left out
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct Expected {
+    #[serde(default, deserialize_with = "answer")]
+    pub part1: Option<String>,
+    #[serde(default, deserialize_with = "answer")]
+    pub part2: Option<String>,
+}
+
+/// answers can be written as strings or, for convenience, integers
+fn answer<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Answer {
+        Int(i64),
+        Text(String),
+    }
+
+    Ok(match Answer::deserialize(deserializer)? {
+        Answer::Int(v) => Some(v.to_string()),
+        Answer::Text(v) => Some(v),
+    })
+}
+
+/// Recorded answers keyed by solution name, loaded from a TOML file like:
+/// ```toml
+/// [day01]
+/// part1 = 1234
+/// part2 = "5678"
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers(BTreeMap<String, Expected>);
+
+impl Answers {
+    /// load the answers at `path`. A file that isn't there has no answers, so every day checked against it is missing
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(err).with_context(|| format!("Failed to read {:?}", path)),
+        };
+        let answers =
+            toml::from_str(&text).with_context(|| format!("Failed to parse {:?}", path))?;
+        Ok(Self(answers))
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Expected> {
+        self.0.get(name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    /// every recorded answer matched
+    Pass,
+    /// at least one recorded answer didn't match
+    Fail,
+    /// there are no recorded answers to check against
+    Missing,
+    /// the solution couldn't be run
+    Error,
+    /// the solution took longer than it was allowed
+    Timeout,
+}
+
+impl Status {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail => "fail",
+            Status::Missing => "missing",
+            Status::Error => "error",
+            Status::Timeout => "timeout",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Mismatch {
+    pub part: usize,
+    pub expected: String,
+    pub actual: String,
+}
+
+/// The outcome of checking a solution's answers
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Verdict {
+    pub name: String,
+    pub status: Status,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub mismatches: Vec<Mismatch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl Verdict {
+    fn detail(&self) -> String {
+        match (&self.error, self.mismatches.is_empty()) {
+            (Some(err), _) => err.clone(),
+            (None, false) => self
+                .mismatches
+                .iter()
+                .map(|m| format!("part {}: expected {}, got {}", m.part, m.expected, m.actual))
+                .collect::<Vec<_>>()
+                .join("; "),
+            _ => String::new(),
+        }
+    }
+}
+
+/// Check a solution's result against its recorded answers, if there are any.
+/// Parts that weren't solved aren't checked
+pub fn check(name: &str, expected: Option<&Expected>, result: Result<DayReport>) -> Verdict {
+    let mut verdict = Verdict {
+        name: name.to_string(),
+        status: Status::Pass,
+        mismatches: vec![],
+        error: None,
+    };
+
+    let report = match result {
+        Err(err) => {
+            verdict.status = if err.downcast_ref::<TimedOut>().is_some() {
+                Status::Timeout
+            } else {
+                Status::Error
+            };
+            verdict.error = Some(format!("{:#}", err));
+            return verdict;
+        }
+        Ok(report) => report,
+    };
+
+    let expected = expected.cloned().unwrap_or_default();
+    let mut checked = 0;
+
+    for (part, expected, actual) in [
+        (1, expected.part1, report.part1),
+        (2, expected.part2, report.part2),
+    ] {
+        if let (Some(expected), Some(actual)) = (expected, actual) {
+            checked += 1;
+            if expected != actual {
+                verdict.mismatches.push(Mismatch {
+                    part,
+                    expected,
+                    actual,
+                });
+            }
+        }
+    }
+
+    if checked == 0 {
+        verdict.status = Status::Missing;
+    } else if !verdict.mismatches.is_empty() {
+        verdict.status = Status::Fail;
+    }
+
+    verdict
+}
+
+/// Print verdicts in the requested format
+pub fn print_verdicts(format: OutputFormat, verdicts: &[Verdict]) {
+    match format {
+        OutputFormat::Text => {
+            for v in verdicts {
+                println!("{}:\t{}\t{}", v.name, v.status.label(), v.detail());
+            }
+
+            let count = |s| verdicts.iter().filter(|v| v.status == s).count();
+            println!(
+                "\nVerified {} solutions: {} passed, {} failed, {} missing, {} errored, {} timed out",
+                verdicts.len(),
+                count(Status::Pass),
+                count(Status::Fail),
+                count(Status::Missing),
+                count(Status::Error),
+                count(Status::Timeout),
+            );
+        }
+        OutputFormat::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(verdicts).expect("verdicts are always serializable")
+        ),
+        OutputFormat::Csv => {
+            println!("name,status,detail");
+            for v in verdicts {
+                println!(
+                    "{},{},\"{}\"",
+                    v.name,
+                    v.status.label(),
+                    v.detail().replace('"', "\"\"")
+                );
+            }
+        }
+        OutputFormat::Markdown => {
+            println!("| Name | Status | Detail |");
+            println!("|------|--------|--------|");
+            for v in verdicts {
+                println!("| {} | {} | {} |", v.name, v.status.label(), v.detail());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::Path, process, time::Duration};
+
+    use anyhow::anyhow;
+
+    use super::{check, Answers, Expected, Mismatch, Status};
+    use crate::runner::isolate::TimedOut;
+    use crate::utils::solver_types::{DayReport, SolverKind};
+
+    fn report(part1: Option<&str>, part2: Option<&str>) -> DayReport {
+        DayReport {
+            kind: SolverKind::Simultaneous,
+            part1: part1.map(str::to_string),
+            part2: part2.map(str::to_string),
+            parse_ms: 0.0,
+            part1_ms: None,
+            part2_ms: None,
+            solve_ms: 0.0,
+            skipped_part2: part2.is_none(),
+        }
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        let answers: std::collections::BTreeMap<String, Expected> = toml::from_str(
+            r#"
+[day13]
+part1 = 480
+part2 = "875318608908"
+
+[day17]
+part1 = "4,6,3,5,6,3,5,2,1,0"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            Expected {
+                part1: Some("480".to_string()),
+                part2: Some("875318608908".to_string())
+            },
+            answers["day13"]
+        );
+        assert_eq!(None, answers["day17"].part2);
+    }
+
+    #[test]
+    fn test_load_answers() {
+        let missing = Answers::load(Path::new("no/such/answers.toml")).unwrap();
+        assert_eq!(None, missing.get("day01"));
+
+        let bad = env::temp_dir().join(format!("aoc_answers_{}.toml", process::id()));
+        fs::write(&bad, "[day01\npart1 = 1").unwrap();
+        let err = Answers::load(&bad);
+        fs::remove_file(&bad).unwrap();
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let expected = Expected {
+            part1: Some("480".to_string()),
+            part2: None,
+        };
+
+        let pass = check("day13", Some(&expected), Ok(report(Some("480"), Some("1"))));
+        assert_eq!(Status::Pass, pass.status);
+
+        let fail = check("day13", Some(&expected), Ok(report(Some("481"), Some("1"))));
+        assert_eq!(Status::Fail, fail.status);
+        assert_eq!(
+            vec![Mismatch {
+                part: 1,
+                expected: "480".to_string(),
+                actual: "481".to_string()
+            }],
+            fail.mismatches
+        );
+
+        let missing = check("day13", None, Ok(report(Some("480"), Some("1"))));
+        assert_eq!(Status::Missing, missing.status);
+
+        // only part 2 was solved, and it has no recorded answer
+        let unchecked = check("day13", Some(&expected), Ok(report(None, Some("1"))));
+        assert_eq!(Status::Missing, unchecked.status);
+
+        let error = check("day13", Some(&expected), Err(anyhow!("no input")));
+        assert_eq!(Status::Error, error.status);
+
+        let timeout = check(
+            "day13",
+            Some(&expected),
+            Err(TimedOut(Duration::from_secs(10)).into()),
+        );
+        assert_eq!(Status::Timeout, timeout.status);
+        assert_eq!(Some("timed out after 10s".to_string()), timeout.error);
+    }
+}
@@ -1,47 +1,123 @@
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
 pub mod templates;
+mod y2024;
 
-use anyhow::Result;
-
-/// Add new solutions to this const
-pub const SOLUTIONS: [fn(&str) -> Result<f32>; 20] = [
-    day01::day01,
-    day02::day02,
-    day03::day03,
-    day04::day04,
-    day05::day05,
-    day06::day06,
-    day07::day07,
-    day08::day08,
-    day09::day09,
-    day10::day10,
-    day11::day11,
-    day12::day12,
-    day13::day13,
-    day14::day14,
-    day15::day15,
-    day16::day16,
-    day17::day17,
-    day18::day18,
-    day19::day19,
-    day20::day20,
-];
+use std::collections::BTreeMap;
+
+use itertools::Itertools;
+use lazy_static::lazy_static;
+
+use crate::utils::solver_types::Solution;
+
+/// Every solution, keyed by year and day. Each year's module registers its own days, which don't have to be solved in order
+#[derive(Default)]
+pub struct Registry {
+    solutions: BTreeMap<(u16, usize), Box<dyn Solution>>,
+}
+
+impl Registry {
+    /// add a solution under the year and day from its info. Registering a day twice is a mistake, so it panics
+    pub fn register(&mut self, solution: impl Solution + 'static) {
+        let info = solution.info();
+        let previous = self
+            .solutions
+            .insert((info.year, info.day), Box::new(solution));
+        assert!(
+            previous.is_none(),
+            "Day {} of {} is registered twice",
+            info.day,
+            info.year
+        );
+    }
+
+    pub fn get(&self, year: u16, day: usize) -> Option<&dyn Solution> {
+        self.solutions.get(&(year, day)).map(|s| s.as_ref())
+    }
+
+    /// the days solved for `year`, in order
+    pub fn days(&self, year: u16) -> Vec<usize> {
+        self.solutions
+            .keys()
+            .filter(|(y, _)| *y == year)
+            .map(|(_, d)| *d)
+            .collect()
+    }
+
+    /// the years with any solutions, in order
+    pub fn years(&self) -> Vec<u16> {
+        self.solutions.keys().map(|(y, _)| *y).dedup().collect()
+    }
+
+    /// the most recent year with any solutions
+    pub fn latest_year(&self) -> Option<u16> {
+        self.solutions.keys().map(|(y, _)| *y).max()
+    }
+}
+
+lazy_static! {
+    /// Add new years to this registry
+    pub static ref REGISTRY: Registry = {
+        let mut registry = Registry::default();
+        y2024::register(&mut registry);
+        registry
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{Registry, REGISTRY};
+    use crate::{
+        runner::select::LAST_DAY,
+        solutions::templates::linear_template::ExampleSolutionLinear,
+        utils::solver_types::{DayInfo, Linear},
+    };
+
+    fn example_day(
+        year: u16,
+        day: usize,
+    ) -> Linear<ExampleSolutionLinear, Vec<usize>, usize, usize> {
+        Linear::new(DayInfo {
+            year,
+            day,
+            title: "Example",
+            parts: 2,
+        })
+    }
+
+    #[test]
+    fn test_registry() {
+        let mut registry = Registry::default();
+        registry.register(example_day(2015, 25));
+        registry.register(example_day(2015, 3));
+        registry.register(example_day(2016, 1));
+
+        assert_eq!(vec![3, 25], registry.days(2015));
+        assert_eq!(vec![2015, 2016], registry.years());
+        assert_eq!(Some(2016), registry.latest_year());
+        assert!(registry.get(2015, 4).is_none());
+        assert_eq!(25, registry.get(2015, 25).unwrap().info().day);
+    }
+
+    #[test]
+    #[should_panic(expected = "registered twice")]
+    fn test_register_twice() {
+        let mut registry = Registry::default();
+        registry.register(example_day(2015, 1));
+        registry.register(example_day(2015, 1));
+    }
+
+    #[test]
+    fn test_solutions_registered() {
+        assert!(!REGISTRY.days(2024).is_empty());
+
+        // every day is registered under its own year and day, so none can hide another
+        let mut seen = HashSet::new();
+        for (&(year, day), solution) in &REGISTRY.solutions {
+            let info = solution.info();
+            assert_eq!((year, day), (info.year, info.day), "{}", info.title);
+            assert!((1..=LAST_DAY).contains(&day), "{}", info.title);
+            assert!(seen.insert((info.year, info.day)), "{}", info.title);
+        }
+    }
+}
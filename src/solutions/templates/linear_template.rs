@@ -1,4 +1,7 @@
-use crate::utils::solver_types::SolutionLinear;
+use crate::utils::{
+    progress::Progress,
+    solver_types::{NoParams, SolutionLinear},
+};
 use anyhow::Result;
 
 // Example:
@@ -8,7 +11,9 @@ use anyhow::Result;
 pub struct ExampleSolutionLinear {}
 
 impl SolutionLinear<Vec<usize>, usize, usize> for ExampleSolutionLinear {
-    fn load(input: &str) -> Result<Vec<usize>> {
+    type Params = NoParams;
+
+    fn load(input: &str, _params: &NoParams) -> Result<Vec<usize>> {
         Ok(input
             .replace(['[', ']'], "")
             .split(',')
@@ -16,11 +21,16 @@ impl SolutionLinear<Vec<usize>, usize, usize> for ExampleSolutionLinear {
             .collect())
     }
 
-    fn part1(input: &mut Vec<usize>) -> Result<usize> {
+    fn part1(input: &mut Vec<usize>, _params: &NoParams, _progress: &Progress) -> Result<usize> {
         Ok(input.iter().sum())
     }
 
-    fn part2(input: &mut Vec<usize>, part_1_solution: usize) -> Result<usize> {
+    fn part2(
+        input: &mut Vec<usize>,
+        part_1_solution: usize,
+        _params: &NoParams,
+        _progress: &Progress,
+    ) -> Result<usize> {
         Ok(input.len() * part_1_solution)
     }
 }
@@ -28,7 +38,10 @@ impl SolutionLinear<Vec<usize>, usize, usize> for ExampleSolutionLinear {
 #[cfg(test)]
 mod tests {
     use super::ExampleSolutionLinear;
-    use crate::utils::solver_types::SolutionLinear;
+    use crate::utils::{
+        progress::Progress,
+        solver_types::{NoParams, SolutionLinear},
+    };
     use rstest::rstest;
 
     #[rstest]
@@ -36,9 +49,11 @@ mod tests {
     #[case("[0,7,13,20,1,100]", 141, 846)]
     #[case("[6000]", 6000, 6000)]
     fn validate_linear(#[case] input: &str, #[case] expected_1: usize, #[case] expected_2: usize) {
-        let mut input = ExampleSolutionLinear::load(input).unwrap();
-        let p1 = ExampleSolutionLinear::part1(&mut input).unwrap();
-        let p2 = ExampleSolutionLinear::part2(&mut input, p1).unwrap();
+        let params = NoParams {};
+        let mut input = ExampleSolutionLinear::load(input, &params).unwrap();
+        let p1 = ExampleSolutionLinear::part1(&mut input, &params, &Progress::silent()).unwrap();
+        let p2 =
+            ExampleSolutionLinear::part2(&mut input, p1, &params, &Progress::silent()).unwrap();
 
         assert_eq!(expected_1, p1);
         assert_eq!(expected_2, p2);
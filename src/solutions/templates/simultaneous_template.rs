@@ -1,4 +1,7 @@
-use crate::utils::solver_types::SolutionSimultaneous;
+use crate::utils::{
+    progress::Progress,
+    solver_types::{NoParams, PartSink, SolutionSimultaneous},
+};
 use anyhow::anyhow;
 use itertools::Itertools;
 
@@ -11,7 +14,9 @@ use itertools::Itertools;
 pub struct ExampleSolutionSimultaneous {}
 
 impl SolutionSimultaneous<Vec<usize>, usize, usize> for ExampleSolutionSimultaneous {
-    fn load(input: &str) -> anyhow::Result<Vec<usize>> {
+    type Params = NoParams;
+
+    fn load(input: &str, _params: &NoParams) -> anyhow::Result<Vec<usize>> {
         Ok(input
             .replace(['[', ']'], "")
             .split(',')
@@ -19,11 +24,18 @@ impl SolutionSimultaneous<Vec<usize>, usize, usize> for ExampleSolutionSimultane
             .collect())
     }
 
-    fn solve(input: Vec<usize>) -> anyhow::Result<(usize, usize)> {
+    fn solve(
+        input: Vec<usize>,
+        sink: &mut PartSink<usize, usize>,
+        _params: &NoParams,
+        _progress: &Progress,
+    ) -> anyhow::Result<()> {
         let mut prev: usize = usize::MAX;
         for (x, y) in input.iter().tuple_windows() {
             if x > &prev {
-                return Ok((*x, *y));
+                sink.part1(*x);
+                sink.part2(*y);
+                return Ok(());
             }
             prev = *x;
         }
@@ -34,7 +46,7 @@ impl SolutionSimultaneous<Vec<usize>, usize, usize> for ExampleSolutionSimultane
 #[cfg(test)]
 mod tests {
     use super::ExampleSolutionSimultaneous;
-    use crate::utils::solver_types::SolutionSimultaneous;
+    use crate::utils::solver_types::{solve_simultaneous, Parts, SolveOptions};
     use rstest::rstest;
 
     #[rstest]
@@ -42,10 +54,14 @@ mod tests {
     #[case("[1,2,6,4,100]", 2, 6)]
     #[case("[5,4,3,1,2,7]", 2, 7)]
     fn validate_simul(#[case] input: &str, #[case] expected_1: usize, #[case] expected_2: usize) {
-        let input = ExampleSolutionSimultaneous::load(input).unwrap();
-        let (p1, p2) = ExampleSolutionSimultaneous::solve(input).unwrap();
+        let report = solve_simultaneous::<ExampleSolutionSimultaneous, _, _, _>(
+            input,
+            &SolveOptions::new(Parts::Both),
+            &mut |_| (),
+        )
+        .unwrap();
 
-        assert_eq!(expected_1, p1);
-        assert_eq!(expected_2, p2);
+        assert_eq!(Some(expected_1.to_string()), report.part1);
+        assert_eq!(Some(expected_2.to_string()), report.part2);
     }
 }
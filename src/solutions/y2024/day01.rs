@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+
+use crate::utils::{
+    parse_error::{parse_int, split_pair},
+    progress::Progress,
+    solver_types::{DayInfo, Linear, NoParams, Solution, SolutionLinear},
+};
+use anyhow::Result;
+
+pub struct Day01Solution {}
+
+pub fn solution() -> impl Solution {
+    Linear::<Day01Solution, _, _, _>::new(DayInfo {
+        year: 2024,
+        day: 1,
+        title: "Historian Hysteria",
+        parts: 2,
+    })
+}
+
+impl SolutionLinear<(Vec<usize>, Vec<usize>), usize, usize> for Day01Solution {
+    type Params = NoParams;
+
+    fn load(input: &str, _params: &NoParams) -> Result<(Vec<usize>, Vec<usize>)> {
+        let (mut l, mut r) = (vec![], vec![]);
+        for i in input.lines() {
+            let (lx, rx) = split_pair(input, i, "   ")?;
+            l.push(parse_int(input, lx)?);
+            r.push(parse_int(input, rx)?);
+        }
+        Ok((l, r))
+    }
+
+    // sort lists, sum diffs
+    fn part1(
+        (l, r): &mut (Vec<usize>, Vec<usize>),
+        _params: &NoParams,
+        _progress: &Progress,
+    ) -> Result<usize> {
+        l.sort();
+        r.sort();
+        Ok(l.iter()
+            .zip(r.iter())
+            .map(|(lx, rx)| lx.max(rx) - lx.min(rx))
+            .sum())
+    }
+
+    fn part2(
+        (l, r): &mut (Vec<usize>, Vec<usize>),
+        _part_1_solution: usize,
+        _params: &NoParams,
+        _progress: &Progress,
+    ) -> Result<usize> {
+        let mut occurrences = HashMap::new();
+        for x in r {
+            occurrences.entry(x).and_modify(|v| *v += 1).or_insert(1);
+        }
+
+        Ok(l.iter().map(|k| k * occurrences.get(k).unwrap_or(&0)).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day01Solution;
+    use crate::{
+        runner::examples::assert_example,
+        utils::solver_types::{NoParams, SolutionLinear},
+    };
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("3   4\n4 3", "line 2, col 1: expected '   ', found '4 3'")]
+    #[case("3   4\n4   x", "line 2, col 5: expected integer, found 'x'")]
+    fn validate_malformed(#[case] input: &str, #[case] expected: &str) {
+        let err = Day01Solution::load(input, &NoParams {}).unwrap_err();
+
+        assert_eq!(expected, err.to_string());
+    }
+
+    #[rstest]
+    #[case(1)]
+    fn validate_day01(#[case] example: usize) {
+        assert_example(2024, 1, example);
+    }
+}
@@ -0,0 +1,82 @@
+use crate::utils::{
+    load_input::try_load_lines,
+    parse_error::parse_int,
+    progress::Progress,
+    solver_types::{DayInfo, Linear, NoParams, Solution, SolutionLinear},
+};
+use anyhow::Result;
+use itertools::Itertools;
+
+pub struct Day02Solution {}
+
+pub fn solution() -> impl Solution {
+    Linear::<Day02Solution, _, _, _>::new(DayInfo {
+        year: 2024,
+        day: 2,
+        title: "Red-Nosed Reports",
+        parts: 2,
+    })
+}
+
+fn is_safe(line: &[usize]) -> bool {
+    let increasing = &line[0].gt(&line[1]);
+
+    line.iter().tuple_windows().all(|(a, b)| {
+        if *increasing != a.gt(b) {
+            return false;
+        }
+        let diff = a.abs_diff(*b);
+        diff > 0 && diff < 4
+    })
+}
+
+fn cooler_is_safe(line: &[usize]) -> bool {
+    // lazy :)
+    let mut muts = vec![line.to_owned()];
+    for i in 0..line.len() {
+        let mut cl = line.to_owned();
+        cl.remove(i);
+        muts.push(cl);
+    }
+
+    muts.iter().any(|v| is_safe(v))
+}
+
+impl SolutionLinear<Vec<Vec<usize>>, usize, usize> for Day02Solution {
+    type Params = NoParams;
+
+    fn load(input: &str, _params: &NoParams) -> Result<Vec<Vec<usize>>> {
+        Ok(try_load_lines(input, |l| {
+            l.split(' ').map(|n| parse_int(input, n)).collect()
+        })?)
+    }
+
+    fn part1(
+        input: &mut Vec<Vec<usize>>,
+        _params: &NoParams,
+        _progress: &Progress,
+    ) -> Result<usize> {
+        Ok(input.iter().filter(|l| is_safe(l)).count())
+    }
+
+    fn part2(
+        input: &mut Vec<Vec<usize>>,
+        _part_1_solution: usize,
+        _params: &NoParams,
+        _progress: &Progress,
+    ) -> Result<usize> {
+        Ok(input.iter().filter(|l| cooler_is_safe(l)).count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::runner::examples::assert_example;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(1)]
+    fn validate_day02(#[case] example: usize) {
+        assert_example(2024, 2, example);
+    }
+}
@@ -1,4 +1,7 @@
-use crate::utils::solver_types::{solve_linear, SolutionLinear};
+use crate::utils::{
+    progress::Progress,
+    solver_types::{DayInfo, Linear, NoParams, Solution, SolutionLinear},
+};
 use anyhow::Result;
 use itertools::Itertools;
 use lazy_static::lazy_static;
@@ -12,8 +15,13 @@ lazy_static! {
 
 pub struct Day03Solution {}
 
-pub fn day03(input: &str) -> Result<f32> {
-    solve_linear::<Day03Solution, _, _, _>(input)
+pub fn solution() -> impl Solution {
+    Linear::<Day03Solution, _, _, _>::new(DayInfo {
+        year: 2024,
+        day: 3,
+        title: "Mull It Over",
+        parts: 2,
+    })
 }
 
 fn find_matches<'a>(input: &'a str, re: &'a Regex) -> impl Iterator<Item = (&'a str, usize)> + 'a {
@@ -29,17 +37,24 @@ fn process_mul(input: &str) -> usize {
 }
 
 impl SolutionLinear<String, usize, usize> for Day03Solution {
-    fn load(input: &str) -> Result<String> {
+    type Params = NoParams;
+
+    fn load(input: &str, _params: &NoParams) -> Result<String> {
         Ok(input.to_string())
     }
 
-    fn part1(input: &mut String) -> Result<usize> {
+    fn part1(input: &mut String, _params: &NoParams, _progress: &Progress) -> Result<usize> {
         Ok(find_matches(input, &MUL_RE)
             .map(|(mul, _)| process_mul(mul))
             .sum())
     }
 
-    fn part2(input: &mut String, _part_1_solution: usize) -> Result<usize> {
+    fn part2(
+        input: &mut String,
+        _part_1_solution: usize,
+        _params: &NoParams,
+        _progress: &Progress,
+    ) -> Result<usize> {
         // find locations for every mul, do, and dont. (assume a pre-string do and a post-string dont)
         let muls = find_matches(input, &MUL_RE).collect_vec();
         let mut dos = (0..1)
@@ -79,30 +94,13 @@ impl SolutionLinear<String, usize, usize> for Day03Solution {
 
 #[cfg(test)]
 mod tests {
-    use super::Day03Solution;
-    use crate::utils::solver_types::SolutionLinear;
+    use crate::runner::examples::assert_example;
     use rstest::rstest;
 
     #[rstest]
-    #[case(
-        "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))",
-        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))",
-        161,
-        48
-    )]
-    fn validate_day03(
-        #[case] input1: &str,
-        #[case] input2: &str,
-        #[case] expected_1: usize,
-        #[case] expected_2: usize,
-    ) {
-        let mut input1 = Day03Solution::load(input1).unwrap();
-        let mut input2 = Day03Solution::load(input2).unwrap();
-
-        let p1 = Day03Solution::part1(&mut input1).unwrap();
-        assert_eq!(expected_1, p1);
-
-        let p2 = Day03Solution::part2(&mut input2, p1).unwrap();
-        assert_eq!(expected_2, p2);
+    #[case(1)]
+    #[case(2)]
+    fn validate_day03(#[case] example: usize) {
+        assert_example(2024, 3, example);
     }
 }
@@ -2,7 +2,8 @@ use crate::utils::{
     grid::Grid,
     load_input::load_2d_grid,
     point::Pt,
-    solver_types::{solve_linear, SolutionLinear},
+    progress::Progress,
+    solver_types::{DayInfo, Linear, NoParams, Solution, SolutionLinear},
 };
 use anyhow::Result;
 use itertools::Itertools;
@@ -11,8 +12,13 @@ pub struct Day04Solution {}
 
 const CROSS: [Pt<2>; 4] = [Pt([-1, -1]), Pt([1, -1]), Pt([-1, 1]), Pt([1, 1])];
 
-pub fn day04(input: &str) -> Result<f32> {
-    solve_linear::<Day04Solution, _, _, _>(input)
+pub fn solution() -> impl Solution {
+    Linear::<Day04Solution, _, _, _>::new(DayInfo {
+        year: 2024,
+        day: 4,
+        title: "Ceres Search",
+        parts: 2,
+    })
 }
 
 type XMASGrid = Grid<char, 2>;
@@ -37,11 +43,13 @@ fn checkx(g: &XMASGrid, pos: &Pt<2>) -> bool {
 }
 
 impl SolutionLinear<XMASGrid, usize, usize> for Day04Solution {
-    fn load(input: &str) -> Result<XMASGrid> {
+    type Params = NoParams;
+
+    fn load(input: &str, _params: &NoParams) -> Result<XMASGrid> {
         Ok(load_2d_grid(input, |x| x))
     }
 
-    fn part1(input: &mut XMASGrid) -> Result<usize> {
+    fn part1(input: &mut XMASGrid, _params: &NoParams, _progress: &Progress) -> Result<usize> {
         Ok(input
             .grid
             .iter()
@@ -51,39 +59,24 @@ impl SolutionLinear<XMASGrid, usize, usize> for Day04Solution {
             .count())
     }
 
-    fn part2(input: &mut XMASGrid, _part_1_solution: usize) -> Result<usize> {
+    fn part2(
+        input: &mut XMASGrid,
+        _part_1_solution: usize,
+        _params: &NoParams,
+        _progress: &Progress,
+    ) -> Result<usize> {
         Ok(input.grid.keys().filter(|k| checkx(input, k)).count())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Day04Solution;
-    use crate::utils::solver_types::SolutionLinear;
+    use crate::runner::examples::assert_example;
     use rstest::rstest;
 
     #[rstest]
-    #[case(
-        "MMMSXXMASM
-MSAMXMSMSA
-AMXSXMAAMM
-MSAMASMSMX
-XMASAMXAMM
-XXAMMXXAMA
-SMSMSASXSS
-SAXAMASAAA
-MAMMMXMMMM
-MXMXAXMASX",
-        18,
-        9
-    )]
-    fn validate_day04(#[case] input: &str, #[case] expected_1: usize, #[case] expected_2: usize) {
-        let mut input = Day04Solution::load(input).unwrap();
-
-        let p1 = Day04Solution::part1(&mut input).unwrap();
-        assert_eq!(expected_1, p1);
-
-        let p2 = Day04Solution::part2(&mut input, p1).unwrap();
-        assert_eq!(expected_2, p2);
+    #[case(1)]
+    fn validate_day04(#[case] example: usize) {
+        assert_example(2024, 4, example);
     }
 }
@@ -0,0 +1,103 @@
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    ops::Div,
+};
+
+use crate::utils::{
+    load_input::{int, lines, list, pair, parse},
+    progress::Progress,
+    solver_types::{DayInfo, Linear, NoParams, Solution, SolutionLinear},
+};
+use anyhow::Result;
+
+pub struct Day05Solution {}
+
+// Hashmap is VAL -> (VALS THAT COME AFTER)
+type Instrs = (HashMap<usize, HashSet<usize>>, Vec<Vec<usize>>);
+
+pub fn solution() -> impl Solution {
+    Linear::<Day05Solution, _, _, _>::new(DayInfo {
+        year: 2024,
+        day: 5,
+        title: "Print Queue",
+        parts: 2,
+    })
+}
+
+fn sort_instr(rules: &HashMap<usize, HashSet<usize>>, instr: &mut [usize]) -> bool {
+    let mut sort_occurred = false;
+    instr.sort_by(|a, b| {
+        if let Some(befores) = rules.get(a) {
+            if befores.contains(b) {
+                sort_occurred = true;
+                return Ordering::Less;
+            }
+        }
+        Ordering::Equal
+    });
+    sort_occurred
+}
+
+impl SolutionLinear<Instrs, usize, usize> for Day05Solution {
+    type Params = NoParams;
+
+    fn load(input: &str, _params: &NoParams) -> Result<Instrs> {
+        let (rules, seqs) = parse(
+            input,
+            pair(
+                lines(pair(int(), "|", int())),
+                "\n\n",
+                lines(list(",", int())),
+            ),
+        )?;
+
+        let rules_proc =
+            rules
+                .into_iter()
+                .fold(HashMap::<usize, HashSet<usize>>::new(), |mut m, (a, b)| {
+                    m.entry(a).or_default().insert(b);
+                    m
+                });
+
+        Ok((rules_proc, seqs))
+    }
+
+    fn part1(
+        (rules, instrs): &mut Instrs,
+        _params: &NoParams,
+        _progress: &Progress,
+    ) -> Result<usize> {
+        let mut out = 0;
+
+        for is in instrs {
+            match sort_instr(rules, is) {
+                true => (),
+                false => out += is[is.len().div(2)],
+            }
+        }
+        Ok(out)
+    }
+
+    fn part2(
+        (_, instrs): &mut Instrs,
+        part_1_solution: usize,
+        _params: &NoParams,
+        _progress: &Progress,
+    ) -> Result<usize> {
+        // part 1 already sorted the vectors, so we just need all the numbers minus part 1
+        Ok(instrs.iter().map(|is| is[is.len().div(2)]).sum::<usize>() - part_1_solution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::runner::examples::assert_example;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(1)]
+    fn validate_day05(#[case] example: usize) {
+        assert_example(2024, 5, example);
+    }
+}
@@ -3,14 +3,20 @@ use std::collections::HashSet;
 use crate::utils::{
     grid::Grid,
     point::Pt,
-    solver_types::{solve_linear, SolutionLinear},
+    progress::Progress,
+    solver_types::{DayInfo, Linear, NoParams, Solution, SolutionLinear},
 };
 use anyhow::Result;
 
 pub struct Day06Solution {}
 
-pub fn day06(input: &str) -> Result<f32> {
-    solve_linear::<Day06Solution, _, _, _>(input)
+pub fn solution() -> impl Solution {
+    Linear::<Day06Solution, _, _, _>::new(DayInfo {
+        year: 2024,
+        day: 6,
+        title: "Guard Gallivant",
+        parts: 2,
+    })
 }
 
 const DIRS: [Pt<2>; 4] = [Pt([0, -1]), Pt([1, 0]), Pt([0, 1]), Pt([-1, 0])];
@@ -44,7 +50,9 @@ fn simulate(maze: &Maze, obs: Pt<2>) -> (HashSet<(Pt<2>, usize)>, bool) {
 }
 
 impl SolutionLinear<Maze, usize, usize> for Day06Solution {
-    fn load(input: &str) -> Result<Maze> {
+    type Params = NoParams;
+
+    fn load(input: &str, _params: &NoParams) -> Result<Maze> {
         let mut guard = Pt::<2>::default();
 
         let mut pairs = vec![];
@@ -64,7 +72,7 @@ impl SolutionLinear<Maze, usize, usize> for Day06Solution {
         })
     }
 
-    fn part1(maze: &mut Maze) -> Result<usize> {
+    fn part1(maze: &mut Maze, _params: &NoParams, _progress: &Progress) -> Result<usize> {
         let destiny = simulate(maze, Pt([-1, -1]));
         // we record the first path so we know where to put obstructions in part 2
         maze.destined_path = destiny.0.iter().map(|(p, _)| *p).collect::<HashSet<_>>();
@@ -72,49 +80,32 @@ impl SolutionLinear<Maze, usize, usize> for Day06Solution {
         Ok(maze.destined_path.len())
     }
 
-    fn part2(maze: &mut Maze, _part_1_solution: usize) -> Result<usize> {
-        Ok(maze
-            .destined_path
-            .iter()
-            .filter(|obs| {
-                if **obs == maze.guard {
-                    false
-                } else {
-                    simulate(maze, **obs).1
-                }
-            })
-            .count())
+    fn part2(
+        maze: &mut Maze,
+        _part_1_solution: usize,
+        _params: &NoParams,
+        progress: &Progress,
+    ) -> Result<usize> {
+        progress.start(maze.destined_path.len());
+        maze.destined_path.iter().try_fold(0, |loops, obs| {
+            progress.tick()?;
+            if *obs == maze.guard || !simulate(maze, *obs).1 {
+                Ok(loops)
+            } else {
+                Ok(loops + 1)
+            }
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Day06Solution;
-    use crate::utils::solver_types::SolutionLinear;
+    use crate::runner::examples::assert_example;
     use rstest::rstest;
 
     #[rstest]
-    #[case(
-        "....#.....
-.........#
-..........
-..#.......
-.......#..
-..........
-.#..^.....
-........#.
-#.........
-......#...",
-        41,
-        6
-    )]
-    fn validate_day06(#[case] input: &str, #[case] expected_1: usize, #[case] expected_2: usize) {
-        let mut input = Day06Solution::load(input).unwrap();
-
-        let p1 = Day06Solution::part1(&mut input).unwrap();
-        assert_eq!(expected_1, p1);
-
-        let p2 = Day06Solution::part2(&mut input, p1).unwrap();
-        assert_eq!(expected_2, p2);
+    #[case(1)]
+    fn validate_day06(#[case] example: usize) {
+        assert_example(2024, 6, example);
     }
 }
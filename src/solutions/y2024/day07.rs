@@ -1,11 +1,20 @@
-use crate::utils::solver_types::{solve_linear, SolutionLinear};
+use crate::utils::{
+    load_input::{int, lines, list, pair, parse},
+    progress::Progress,
+    solver_types::{DayInfo, Linear, NoParams, Solution, SolutionLinear},
+};
 use anyhow::Result;
 use std::cmp::Ordering;
 
 pub struct Day07Solution {}
 
-pub fn day07(input: &str) -> Result<f32> {
-    solve_linear::<Day07Solution, _, _, _>(input)
+pub fn solution() -> impl Solution {
+    Linear::<Day07Solution, _, _, _>::new(DayInfo {
+        year: 2024,
+        day: 7,
+        title: "Bridge Repair",
+        parts: 2,
+    })
 }
 
 fn add(a: usize, b: usize) -> usize {
@@ -46,20 +55,17 @@ fn validate(
 }
 
 impl SolutionLinear<Vec<(usize, Vec<usize>)>, usize, usize> for Day07Solution {
-    fn load(input: &str) -> Result<Vec<(usize, Vec<usize>)>> {
-        Ok(input
-            .lines()
-            .map(|l| {
-                let (a, b) = l.split_once(": ").unwrap();
-                (
-                    a.parse().unwrap(),
-                    b.split(' ').map(|c| c.parse().unwrap()).collect(),
-                )
-            })
-            .collect())
+    type Params = NoParams;
+
+    fn load(input: &str, _params: &NoParams) -> Result<Vec<(usize, Vec<usize>)>> {
+        Ok(parse(input, lines(pair(int(), ": ", list(" ", int()))))?)
     }
 
-    fn part1(input: &mut Vec<(usize, Vec<usize>)>) -> Result<usize> {
+    fn part1(
+        input: &mut Vec<(usize, Vec<usize>)>,
+        _params: &NoParams,
+        _progress: &Progress,
+    ) -> Result<usize> {
         Ok(input
             .iter()
             .filter(|i| validate(i, &[&add, &mul], 0, 0, &add))
@@ -67,7 +73,12 @@ impl SolutionLinear<Vec<(usize, Vec<usize>)>, usize, usize> for Day07Solution {
             .sum())
     }
 
-    fn part2(input: &mut Vec<(usize, Vec<usize>)>, _part_1_solution: usize) -> Result<usize> {
+    fn part2(
+        input: &mut Vec<(usize, Vec<usize>)>,
+        _part_1_solution: usize,
+        _params: &NoParams,
+        _progress: &Progress,
+    ) -> Result<usize> {
         Ok(input
             .iter()
             .filter(|i| validate(i, &[&concat, &add, &mul], 0, 0, &add))
@@ -78,31 +89,12 @@ impl SolutionLinear<Vec<(usize, Vec<usize>)>, usize, usize> for Day07Solution {
 
 #[cfg(test)]
 mod tests {
-    use super::Day07Solution;
-    use crate::utils::solver_types::SolutionLinear;
+    use crate::runner::examples::assert_example;
     use rstest::rstest;
 
     #[rstest]
-    #[case(
-        "190: 10 19
-3267: 81 40 27
-83: 17 5
-156: 15 6
-7290: 6 8 6 15
-161011: 16 10 13
-192: 17 8 14
-21037: 9 7 18 13
-292: 11 6 16 20",
-        3749,
-        11387
-    )]
-    fn validate_day07(#[case] input: &str, #[case] expected_1: usize, #[case] expected_2: usize) {
-        let mut input = Day07Solution::load(input).unwrap();
-
-        let p1 = Day07Solution::part1(&mut input).unwrap();
-        assert_eq!(expected_1, p1);
-
-        let p2 = Day07Solution::part2(&mut input, p1).unwrap();
-        assert_eq!(expected_2, p2);
+    #[case(1)]
+    fn validate_day07(#[case] example: usize) {
+        assert_example(2024, 7, example);
     }
 }
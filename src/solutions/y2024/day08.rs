@@ -2,15 +2,21 @@ use std::{collections::HashMap, ops::Mul};
 
 use crate::utils::{
     point::Pt,
-    solver_types::{solve_linear, SolutionLinear},
+    progress::Progress,
+    solver_types::{DayInfo, Linear, NoParams, Solution, SolutionLinear},
 };
 use anyhow::Result;
 use itertools::Itertools;
 
 pub struct Day08Solution {}
 
-pub fn day08(input: &str) -> Result<f32> {
-    solve_linear::<Day08Solution, _, _, _>(input)
+pub fn solution() -> impl Solution {
+    Linear::<Day08Solution, _, _, _>::new(DayInfo {
+        year: 2024,
+        day: 8,
+        title: "Resonant Collinearity",
+        parts: 2,
+    })
 }
 
 struct G {
@@ -56,12 +62,14 @@ fn antinodes_for<'a>(
 }
 
 impl SolutionLinear<G, usize, usize> for Day08Solution {
-    fn load(input: &str) -> Result<G> {
+    type Params = NoParams;
+
+    fn load(input: &str, _params: &NoParams) -> Result<G> {
         let mut locs: HashMap<char, Vec<Pt<2>>> = HashMap::new();
         let mut max_x = 0;
         let mut max_y = 0;
 
-        for (y, line) in (0..).zip(input.split('\n')) {
+        for (y, line) in (0..).zip(input.lines()) {
             for (x, c) in (0..).zip(line.chars()) {
                 if c != '.' {
                     locs.entry(c).or_default().push(Pt([x, y]));
@@ -77,7 +85,7 @@ impl SolutionLinear<G, usize, usize> for Day08Solution {
         })
     }
 
-    fn part1(input: &mut G) -> Result<usize> {
+    fn part1(input: &mut G, _params: &NoParams, _progress: &Progress) -> Result<usize> {
         Ok(input
             .locs
             .values()
@@ -86,7 +94,12 @@ impl SolutionLinear<G, usize, usize> for Day08Solution {
             .count())
     }
 
-    fn part2(input: &mut G, _part_1_solution: usize) -> Result<usize> {
+    fn part2(
+        input: &mut G,
+        _part_1_solution: usize,
+        _params: &NoParams,
+        _progress: &Progress,
+    ) -> Result<usize> {
         Ok(input
             .locs
             .values()
@@ -98,34 +111,12 @@ impl SolutionLinear<G, usize, usize> for Day08Solution {
 
 #[cfg(test)]
 mod tests {
-    use super::Day08Solution;
-    use crate::utils::solver_types::SolutionLinear;
+    use crate::runner::examples::assert_example;
     use rstest::rstest;
 
     #[rstest]
-    #[case(
-        "............
-........0...
-.....0......
-.......0....
-....0.......
-......A.....
-............
-............
-........A...
-.........A..
-............
-............",
-        14,
-        34
-    )]
-    fn validate_day08(#[case] input: &str, #[case] expected_1: usize, #[case] expected_2: usize) {
-        let mut input = Day08Solution::load(input).unwrap();
-
-        let p1 = Day08Solution::part1(&mut input).unwrap();
-        assert_eq!(expected_1, p1);
-
-        let p2 = Day08Solution::part2(&mut input, p1).unwrap();
-        assert_eq!(expected_2, p2);
+    #[case(1)]
+    fn validate_day08(#[case] example: usize) {
+        assert_example(2024, 8, example);
     }
 }
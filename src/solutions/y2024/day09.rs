@@ -1,12 +1,21 @@
 use std::collections::HashMap;
 
-use crate::utils::solver_types::{solve_linear, SolutionLinear};
+use crate::utils::{
+    parse_error::ParseError,
+    progress::Progress,
+    solver_types::{DayInfo, Linear, NoParams, Solution, SolutionLinear},
+};
 use anyhow::Result;
 use itertools::Itertools;
 pub struct Day09Solution {}
 
-pub fn day09(input: &str) -> Result<f32> {
-    solve_linear::<Day09Solution, _, _, _>(input)
+pub fn solution() -> impl Solution {
+    Linear::<Day09Solution, _, _, _>::new(DayInfo {
+        year: 2024,
+        day: 9,
+        title: "Disk Fragmenter",
+        parts: 2,
+    })
 }
 
 #[derive(Default, Debug, Clone)]
@@ -64,18 +73,16 @@ fn block_compress(mut files: Files) -> Files {
         let ixs = files.allocated.get(&f).unwrap().clone();
 
         match find_contig(&contigs, ixs.len()) {
-            None => (),
-            Some(i) => {
-                if contigs[i][0] < ixs[0] {
-                    let x = contigs[i].clone();
+            Some(i) if contigs[i][0] < ixs[0] => {
+                let x = contigs[i].clone();
 
-                    let (used, free) = x.split_at(ixs.len());
+                let (used, free) = x.split_at(ixs.len());
 
-                    contigs[i] = free.to_vec();
-                    insert_contig(&mut contigs, files.allocated.get(&f).unwrap().to_vec());
-                    files.allocated.insert(f, used.to_vec());
-                }
+                contigs[i] = free.to_vec();
+                insert_contig(&mut contigs, files.allocated.get(&f).unwrap().to_vec());
+                files.allocated.insert(f, used.to_vec());
             }
+            _ => (),
         }
         f -= 1;
     }
@@ -117,10 +124,21 @@ fn checksum(files: &Files) -> usize {
 }
 
 impl SolutionLinear<Files, usize, usize> for Day09Solution {
-    fn load(input: &str) -> Result<Files> {
-        Ok(input
-            .chars()
-            .map(|c| c.to_digit(10).unwrap() as usize)
+    type Params = NoParams;
+
+    fn load(input: &str, _params: &NoParams) -> Result<Files> {
+        let disk = input.trim_end();
+        let digits = disk
+            .char_indices()
+            .map(|(i, c)| {
+                c.to_digit(10)
+                    .map(|d| d as usize)
+                    .ok_or_else(|| ParseError::at(input, &disk[i..i + c.len_utf8()], "digit"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(digits
+            .into_iter()
             .chunks(2)
             .into_iter()
             .zip(0_usize..)
@@ -144,13 +162,18 @@ impl SolutionLinear<Files, usize, usize> for Day09Solution {
             }))
     }
 
-    fn part1(input: &mut Files) -> Result<usize> {
+    fn part1(input: &mut Files, _params: &NoParams, _progress: &Progress) -> Result<usize> {
         let compressed = compress(input.clone());
 
         Ok(checksum(&compressed))
     }
 
-    fn part2(input: &mut Files, _part_1_solution: usize) -> Result<usize> {
+    fn part2(
+        input: &mut Files,
+        _part_1_solution: usize,
+        _params: &NoParams,
+        _progress: &Progress,
+    ) -> Result<usize> {
         let compressed = block_compress(input.clone());
 
         Ok(checksum(&compressed))
@@ -159,19 +182,12 @@ impl SolutionLinear<Files, usize, usize> for Day09Solution {
 
 #[cfg(test)]
 mod tests {
-    use super::Day09Solution;
-    use crate::utils::solver_types::SolutionLinear;
+    use crate::runner::examples::assert_example;
     use rstest::rstest;
 
     #[rstest]
-    #[case("2333133121414131402", 1928, 2858)]
-    fn validate_day09(#[case] input: &str, #[case] expected_1: usize, #[case] expected_2: usize) {
-        let mut input = Day09Solution::load(input).unwrap();
-
-        let p1 = Day09Solution::part1(&mut input).unwrap();
-        assert_eq!(expected_1, p1);
-
-        let p2 = Day09Solution::part2(&mut input, p1).unwrap();
-        assert_eq!(expected_2, p2);
+    #[case(1)]
+    fn validate_day09(#[case] example: usize) {
+        assert_example(2024, 9, example);
     }
 }
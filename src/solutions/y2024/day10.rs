@@ -1,16 +1,22 @@
 use crate::utils::{
     grid::Grid,
-    load_input::load_2d_grid,
+    load_input::try_load_2d_grid,
     point::Pt,
-    solver_types::{solve_linear, SolutionLinear},
+    progress::Progress,
+    solver_types::{DayInfo, Linear, NoParams, Solution, SolutionLinear},
 };
 use anyhow::Result;
 use itertools::Itertools;
 
 pub struct Day10Solution {}
 
-pub fn day10(input: &str) -> Result<f32> {
-    solve_linear::<Day10Solution, _, _, _>(input)
+pub fn solution() -> impl Solution {
+    Linear::<Day10Solution, _, _, _>::new(DayInfo {
+        year: 2024,
+        day: 10,
+        title: "Hoof It",
+        parts: 2,
+    })
 }
 
 type Map = Grid<u8, 2>;
@@ -42,20 +48,27 @@ fn resolve_trail(m: &Map, trail: Vec<&Pt<2>>) -> Vec<Pt<2>> {
 }
 
 impl SolutionLinear<Map, usize, usize> for Day10Solution {
-    fn load(input: &str) -> Result<Map> {
-        Ok(load_2d_grid(input, |c| {
-            c.to_digit(10).unwrap().try_into().unwrap()
-        }))
+    type Params = NoParams;
+
+    fn load(input: &str, _params: &NoParams) -> Result<Map> {
+        Ok(try_load_2d_grid(input, "height", |c| {
+            c.to_digit(10).map(|h| h as u8)
+        })?)
     }
 
-    fn part1(input: &mut Map) -> Result<usize> {
+    fn part1(input: &mut Map, _params: &NoParams, _progress: &Progress) -> Result<usize> {
         Ok(find(input, &0)
             .iter()
             .map(|at| resolve_trail(input, vec![at]).iter().unique().count())
             .sum())
     }
 
-    fn part2(input: &mut Map, _part_1_solution: usize) -> Result<usize> {
+    fn part2(
+        input: &mut Map,
+        _part_1_solution: usize,
+        _params: &NoParams,
+        _progress: &Progress,
+    ) -> Result<usize> {
         Ok(find(input, &0)
             .iter()
             .map(|at| resolve_trail(input, vec![at]).len())
@@ -65,30 +78,12 @@ impl SolutionLinear<Map, usize, usize> for Day10Solution {
 
 #[cfg(test)]
 mod tests {
-    use super::Day10Solution;
-    use crate::utils::solver_types::SolutionLinear;
+    use crate::runner::examples::assert_example;
     use rstest::rstest;
 
     #[rstest]
-    #[case(
-        "89010123
-78121874
-87430965
-96549874
-45678903
-32019012
-01329801
-10456732",
-        36,
-        81
-    )]
-    fn validate_day10(#[case] input: &str, #[case] expected_1: usize, #[case] expected_2: usize) {
-        let mut input = Day10Solution::load(input).unwrap();
-
-        let p1 = Day10Solution::part1(&mut input).unwrap();
-        assert_eq!(expected_1, p1);
-
-        let p2 = Day10Solution::part2(&mut input, p1).unwrap();
-        assert_eq!(expected_2, p2);
+    #[case(1)]
+    fn validate_day10(#[case] example: usize) {
+        assert_example(2024, 10, example);
     }
 }
@@ -0,0 +1,157 @@
+use std::{collections::HashMap, ops::Div};
+
+use crate::utils::{
+    parse_error::parse_int,
+    progress::Progress,
+    solver_types::{DayInfo, Linear, Solution, SolutionLinear},
+};
+use anyhow::Result;
+use serde::Deserialize;
+
+pub struct Day11Solution {}
+
+pub fn solution() -> impl Solution {
+    Linear::<Day11Solution, _, _, _>::new(DayInfo {
+        year: 2024,
+        day: 11,
+        title: "Plutonian Pebbles",
+        parts: 2,
+    })
+}
+
+/// How many times we blink in each part
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day11Params {
+    blinks: usize,
+    part2_blinks: usize,
+}
+
+impl Default for Day11Params {
+    fn default() -> Self {
+        Self {
+            blinks: 25,
+            part2_blinks: 75,
+        }
+    }
+}
+
+// idea: rocks can be handled independently.
+// idea: the outcome of a single rock is deterministic -> we can cache if we're smart.
+
+// type (current rock value, steps remaining) -> outcomes where index is steps ahead
+type RockLog = HashMap<(usize, usize), usize>;
+
+fn split(rock: usize) -> (usize, usize) {
+    let digits = digits(rock);
+    let a = rock.div(10_usize.pow(digits / 2));
+    let b = rock - a * 10_usize.pow(digits / 2);
+    (a, b)
+}
+
+fn digits(rock: usize) -> u32 {
+    rock.checked_ilog10().unwrap_or(0) + 1
+}
+
+fn process_rock(log: &mut RockLog, rock: usize, steps_remaining: usize) -> usize {
+    //println!("{}", rock);
+    // shortcut if final step. this is a rock! wow.
+    if steps_remaining == 0 {
+        return 1;
+    }
+    // if we've seen this before, return notified value
+    if let Some(v) = log.get(&(rock, steps_remaining)) {
+        return *v;
+    }
+
+    // process rock
+    let res = match rock {
+        0 => process_rock(log, 1, steps_remaining - 1),
+        v if digits(v) % 2 == 0 => {
+            let (a, b) = split(v);
+            process_rock(log, a, steps_remaining - 1) + process_rock(log, b, steps_remaining - 1)
+        }
+        v => process_rock(log, v * 2024, steps_remaining - 1),
+    };
+
+    log.insert((rock, steps_remaining), res);
+    res
+}
+
+fn solve(input: Vec<usize>, steps: usize) -> usize {
+    let mut log = RockLog::new();
+    let mut sum = 0;
+
+    for rock in input {
+        sum += process_rock(&mut log, rock, steps);
+    }
+    sum
+}
+
+impl SolutionLinear<Vec<usize>, usize, usize> for Day11Solution {
+    type Params = Day11Params;
+
+    fn load(input: &str, _params: &Day11Params) -> Result<Vec<usize>> {
+        Ok(input
+            .trim_end()
+            .split(' ')
+            .map(|rock| parse_int(input, rock))
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(input: &mut Vec<usize>, params: &Day11Params, _progress: &Progress) -> Result<usize> {
+        Ok(solve(input.to_vec(), params.blinks))
+    }
+
+    fn part2(
+        input: &mut Vec<usize>,
+        _part_1_solution: usize,
+        params: &Day11Params,
+        _progress: &Progress,
+    ) -> Result<usize> {
+        Ok(solve(input.to_vec(), params.part2_blinks))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{solution, split};
+    use crate::{
+        runner::examples::assert_example,
+        utils::solver_types::{Parts, RawParams, Solution, SolveOptions},
+    };
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(1000, 10, 0)]
+    #[case(2024, 20, 24)]
+    fn validate_split(#[case] input: usize, #[case] expected_1: usize, #[case] expected_2: usize) {
+        let (a, b) = split(input);
+
+        assert_eq!(expected_1, a);
+        assert_eq!(expected_2, b);
+    }
+
+    #[rstest]
+    #[case(1, 3)]
+    #[case(6, 22)]
+    fn validate_blinks(#[case] blinks: i64, #[case] expected: usize) {
+        let mut params = RawParams::new();
+        params.insert("blinks".to_string(), blinks.into());
+        let options = SolveOptions {
+            parts: Parts::Part1,
+            params,
+            ..SolveOptions::default()
+        };
+
+        let report = solution().solve("125 17", &options).unwrap();
+
+        assert_eq!(Some(expected.to_string()), report.part1);
+    }
+
+    #[rstest]
+    #[case(1)]
+    fn validate_day11(#[case] example: usize) {
+        assert_example(2024, 11, example);
+    }
+}
@@ -7,7 +7,8 @@ use crate::utils::{
     grid::Grid,
     load_input::load_2d_grid,
     point::Pt,
-    solver_types::{solve_linear, SolutionLinear},
+    progress::Progress,
+    solver_types::{DayInfo, Linear, NoParams, Solution, SolutionLinear},
 };
 use anyhow::Result;
 use itertools::Itertools;
@@ -15,8 +16,13 @@ use itertools::Itertools;
 /// this one is pretty messy. mostly stream of consciousness. if i find time i'll neaten it up later.
 pub struct Day12Solution {}
 
-pub fn day12(input: &str) -> Result<f32> {
-    solve_linear::<Day12Solution, _, _, _>(input)
+pub fn solution() -> impl Solution {
+    Linear::<Day12Solution, _, _, _>::new(DayInfo {
+        year: 2024,
+        day: 12,
+        title: "Garden Groups",
+        parts: 2,
+    })
 }
 
 const OFFS: [Pt<2>; 4] = [Pt([0, -1]), Pt([1, 0]), Pt([0, 1]), Pt([-1, 0])];
@@ -116,55 +122,39 @@ fn bulk_price(plot: &HashSet<Pt<2>>) -> usize {
 }
 
 impl SolutionLinear<Vec<HashSet<Pt<2>>>, usize, usize> for Day12Solution {
-    fn load(input: &str) -> Result<Vec<HashSet<Pt<2>>>> {
+    type Params = NoParams;
+
+    fn load(input: &str, _params: &NoParams) -> Result<Vec<HashSet<Pt<2>>>> {
         Ok(to_plots(&load_2d_grid(input, identity)))
     }
 
-    fn part1(input: &mut Vec<HashSet<Pt<2>>>) -> Result<usize> {
+    fn part1(
+        input: &mut Vec<HashSet<Pt<2>>>,
+        _params: &NoParams,
+        _progress: &Progress,
+    ) -> Result<usize> {
         Ok(input.iter().map(price).sum())
     }
 
-    fn part2(input: &mut Vec<HashSet<Pt<2>>>, _part_1_solution: usize) -> Result<usize> {
+    fn part2(
+        input: &mut Vec<HashSet<Pt<2>>>,
+        _part_1_solution: usize,
+        _params: &NoParams,
+        _progress: &Progress,
+    ) -> Result<usize> {
         Ok(input.iter().map(bulk_price).sum())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Day12Solution;
-    use crate::utils::solver_types::SolutionLinear;
+    use crate::runner::examples::assert_example;
     use rstest::rstest;
 
     #[rstest]
-    #[case(
-        "AAAA
-BBCD
-BBCC
-EEEC",
-        140,
-        80
-    )]
-    #[case(
-        "RRRRIICCFF
-RRRRIICCCF
-VVRRRCCFFF
-VVRCCCJFFF
-VVVVCJJCFE
-VVIVCCJJEE
-VVIIICJJEE
-MIIIIIJJEE
-MIIISIJEEE
-MMMISSJEEE",
-        1930,
-        1206
-    )]
-    fn validate_day12(#[case] input: &str, #[case] expected_1: usize, #[case] expected_2: usize) {
-        let mut input = Day12Solution::load(input).unwrap();
-
-        let p1 = Day12Solution::part1(&mut input).unwrap();
-        assert_eq!(expected_1, p1);
-
-        let p2 = Day12Solution::part2(&mut input, p1).unwrap();
-        assert_eq!(expected_2, p2);
+    #[case(1)]
+    #[case(2)]
+    fn validate_day12(#[case] example: usize) {
+        assert_example(2024, 12, example);
     }
 }
@@ -0,0 +1,182 @@
+use crate::utils::{
+    load_input::{ints_n, list, parse},
+    progress::Progress,
+    solver_types::{DayInfo, Linear, Solution, SolutionLinear},
+};
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+pub struct Day13Solution {}
+
+pub fn solution() -> impl Solution {
+    Linear::<Day13Solution, _, _, _>::new(DayInfo {
+        year: 2024,
+        day: 13,
+        title: "Claw Contraption",
+        parts: 2,
+    })
+}
+
+/// Part 1 can press each button at most `press_limit` times, and part 2 moves every prize `offset` further away
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day13Params {
+    press_limit: isize,
+    offset: isize,
+}
+
+impl Default for Day13Params {
+    fn default() -> Self {
+        Self {
+            press_limit: 100,
+            offset: 10_000_000_000_000,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Machine {
+    a: [isize; 2],
+    b: [isize; 2],
+    prize: [isize; 2],
+}
+
+/// solving linear equations, we reorganise:
+/// - Ax_1+Bx_2 = P_x
+/// - Ay_1+By_2 = P_y
+///
+/// to get:
+/// B = (P_y*x_1-P_x*y_1)/(y_2*x_2-x_2*y_1)
+/// A = (P_x-B*x_2)/(x_1)
+/// If B is not an integer, there is no solution!
+/// Neither button can be pressed more than `limit` times, if there is one
+fn solve_machine(m: &Machine, limit: Option<isize>) -> Option<usize> {
+    let n = m.prize[1] * m.a[0] - m.prize[0] * m.a[1];
+    let d = m.b[1] * m.a[0] - m.b[0] * m.a[1];
+    if n % d != 0 {
+        return None;
+    }
+    let b_presses = n.checked_div_euclid(d)?;
+
+    let n2 = m.prize[0] - b_presses * m.b[0];
+    if n2 % m.a[0] != 0 {
+        return None;
+    }
+    let a_presses = (m.prize[0] - b_presses * m.b[0]).checked_div(m.a[0])?;
+
+    if limit.is_some_and(|l| !(0..=l).contains(&a_presses) || !(0..=l).contains(&b_presses)) {
+        None
+    } else {
+        //println!("{:?}: A: {}, B: {}", m, a_presses, b_presses);
+        Some((3 * a_presses + b_presses).try_into().unwrap())
+    }
+}
+
+impl SolutionLinear<Vec<Machine>, usize, usize> for Day13Solution {
+    type Params = Day13Params;
+
+    fn load(input: &str, _params: &Day13Params) -> Result<Vec<Machine>> {
+        // I know engineers who use regex, and they're all cowards.
+        let machine = |input: &str, m: &str| {
+            let [ax, ay, bx, by, px, py] = ints_n(input, m)?;
+            Ok(Machine {
+                a: [ax, ay],
+                b: [bx, by],
+                prize: [px, py],
+            })
+        };
+
+        Ok(parse(input, list("\n\n", machine))?)
+    }
+
+    fn part1(
+        input: &mut Vec<Machine>,
+        params: &Day13Params,
+        _progress: &Progress,
+    ) -> Result<usize> {
+        Ok(input
+            .iter()
+            .filter_map(|m| solve_machine(m, Some(params.press_limit)))
+            .sum())
+    }
+
+    fn part2(
+        input: &mut Vec<Machine>,
+        _part_1_solution: usize,
+        params: &Day13Params,
+        _progress: &Progress,
+    ) -> Result<usize> {
+        let offset = |p: isize| {
+            p.checked_add(params.offset)
+                .ok_or_else(|| anyhow!("Moving the prize at {} by {} overflows", p, params.offset))
+        };
+        for m in input.iter_mut() {
+            m.prize = [offset(m.prize[0])?, offset(m.prize[1])?];
+        }
+
+        Ok(input.iter().filter_map(|m| solve_machine(m, None)).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{solve_machine, Day13Params, Day13Solution, Machine};
+    use crate::{
+        runner::examples::assert_example,
+        utils::{progress::Progress, solver_types::SolutionLinear},
+    };
+    use rstest::rstest;
+
+    #[test]
+    fn validate_press_limit() {
+        // needs 150 A presses and 10 B presses, which is over the part 1 limit
+        let m = Machine {
+            a: [11, 12],
+            b: [13, 14],
+            prize: [1780, 1940],
+        };
+
+        assert_eq!(None, solve_machine(&m, Some(100)));
+        assert_eq!(Some(460), solve_machine(&m, Some(150)));
+        assert_eq!(Some(460), solve_machine(&m, None));
+    }
+
+    #[rstest]
+    #[case(
+        "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n\nButton A: X+1, Y+\nButton B: X+1, Y+1\nPrize: X=1, Y=1",
+        "line 5, col 1: expected 6 integers, found 'Button A: X+1, Y+...'"
+    )]
+    #[case(
+        "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=99999999999999999999",
+        "line 3, col 18: expected integer, found '99999999999999999999'"
+    )]
+    fn validate_malformed(#[case] input: &str, #[case] expected: &str) {
+        let err = Day13Solution::load(input, &Day13Params::default()).unwrap_err();
+
+        assert_eq!(expected, err.to_string());
+    }
+
+    #[test]
+    fn test_offset_overflow() {
+        let mut machines = Day13Solution::load(
+            "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400",
+            &Day13Params::default(),
+        )
+        .unwrap();
+        let params = Day13Params {
+            offset: isize::MAX,
+            ..Day13Params::default()
+        };
+
+        let err = Day13Solution::part2(&mut machines, 0, &params, &Progress::silent()).unwrap_err();
+        assert_eq!(
+            format!("Moving the prize at 8400 by {} overflows", isize::MAX),
+            err.to_string()
+        );
+    }
+
+    #[rstest]
+    #[case(1)]
+    fn validate_day13(#[case] example: usize) {
+        assert_example(2024, 13, example);
+    }
+}
@@ -0,0 +1,168 @@
+use std::cmp::Ordering;
+
+use crate::utils::{
+    grid::Grid,
+    load_input::{ints_n, lines, parse},
+    point::Pt,
+    progress::Progress,
+    solver_types::{DayInfo, Linear, Solution, SolutionLinear},
+};
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use serde::Deserialize;
+
+pub struct Day14Solution {}
+
+pub fn solution() -> impl Solution {
+    Linear::<Day14Solution, _, _, _>::new(DayInfo {
+        year: 2024,
+        day: 14,
+        title: "Restroom Redoubt",
+        parts: 2,
+    })
+}
+
+type Bots = Vec<(Pt<2>, Pt<2>)>;
+
+/// The size of the room
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day14Params {
+    width: isize,
+    height: isize,
+}
+
+impl Default for Day14Params {
+    fn default() -> Self {
+        Self {
+            width: 101,
+            height: 103,
+        }
+    }
+}
+
+fn adjust(start: isize, stepped: isize, bound: isize) -> isize {
+    (bound + start + (stepped % bound)) % bound
+}
+
+fn simulate(state: &Bots, b_x: isize, b_y: isize, steps: isize) -> Bots {
+    state
+        .iter()
+        .map(|(b_p, b_v)| {
+            let adj_p_x = adjust(b_p.0[0], steps * b_v.0[0], b_x);
+            let adj_p_y = adjust(b_p.0[1], steps * b_v.0[1], b_y);
+
+            (Pt([adj_p_x, adj_p_y]), *b_v)
+        })
+        .collect()
+}
+
+fn calc_quads(bots: &Bots, quad_x: isize, quad_y: isize) -> [usize; 4] {
+    bots.iter().fold([0, 0, 0, 0], |mut qs, (Pt([x, y]), _)| {
+        match (x.cmp(&quad_x), y.cmp(&quad_y)) {
+            (Ordering::Less, Ordering::Less) => qs[0] += 1,
+            (Ordering::Greater, Ordering::Less) => qs[1] += 1,
+            (Ordering::Less, Ordering::Greater) => qs[2] += 1,
+            (Ordering::Greater, Ordering::Greater) => qs[3] += 1,
+            _ => (),
+        }
+
+        qs
+    })
+}
+
+#[allow(dead_code)]
+fn print(bots: &Bots) -> String {
+    let mut g = Grid::default();
+
+    for (Pt([x, y]), _) in bots {
+        *g.grid.entry(Pt([*x, *y])).or_default() += 1;
+    }
+
+    g.print(|v| match v {
+        0 => '.',
+        v => char::from_digit(v, 10).unwrap(),
+    })
+}
+
+impl SolutionLinear<Bots, usize, usize> for Day14Solution {
+    type Params = Day14Params;
+
+    fn load(input: &str, room: &Day14Params) -> Result<Bots> {
+        // bots wrap around the room's edges, which needs some room to wrap around
+        for (name, size) in [("width", room.width), ("height", room.height)] {
+            if size <= 0 {
+                return Err(anyhow!(
+                    "The room's {} must be positive, not {}",
+                    name,
+                    size
+                ));
+            }
+        }
+
+        Ok(parse(
+            input,
+            lines(|input: &str, l: &str| {
+                let [px, py, vx, vy] = ints_n(input, l)?;
+                Ok((Pt([px, py]), Pt([vx, vy])))
+            }),
+        )?)
+    }
+
+    fn part1(bots: &mut Bots, room: &Day14Params, _progress: &Progress) -> Result<usize> {
+        let moved = simulate(bots, room.width, room.height, 100);
+
+        Ok(calc_quads(&moved, room.width / 2, room.height / 2)
+            .iter()
+            .product())
+    }
+
+    fn part2(
+        bots: &mut Bots,
+        _part_1_solution: usize,
+        room: &Day14Params,
+        progress: &Progress,
+    ) -> Result<usize> {
+        progress.start(10_000);
+        for i in 0..10_000 {
+            progress.tick()?;
+            let moved_b = simulate(bots, room.width, room.height, i);
+            if moved_b.iter().map(|(p, _)| p).unique().count() == bots.len() {
+                //println!("{}", print(&moved_b));
+                return Ok(i.try_into().unwrap());
+            }
+        }
+
+        panic!("No tree found.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        runner::examples::assert_example,
+        utils::solver_types::{Parts, RawParams, Solution, SolveOptions},
+    };
+    use rstest::rstest;
+
+    use super::solution;
+
+    #[rstest]
+    #[case(1)]
+    fn validate_day14(#[case] example: usize) {
+        assert_example(2024, 14, example);
+    }
+
+    #[rstest]
+    #[case("width = 0", "The room's width must be positive, not 0")]
+    #[case("height = -7", "The room's height must be positive, not -7")]
+    fn validate_room_size(#[case] params: &str, #[case] expected: &str) {
+        let options = SolveOptions {
+            params: toml::from_str::<RawParams>(params).unwrap(),
+            ..SolveOptions::new(Parts::Part1)
+        };
+
+        let err = solution().solve("p=0,4 v=3,-3\n", &options).unwrap_err();
+        assert_eq!(expected, err.to_string());
+    }
+}
@@ -2,16 +2,23 @@ use std::ops::Mul;
 
 use crate::utils::{
     grid::Grid,
+    parse_error::ParseError,
     point::Pt,
-    solver_types::{solve_linear, SolutionLinear},
+    progress::Progress,
+    solver_types::{DayInfo, Linear, NoParams, Solution, SolutionLinear},
 };
 use anyhow::Result;
 use itertools::Itertools;
 
 pub struct Day15Solution {}
 
-pub fn day15(input: &str) -> Result<f32> {
-    solve_linear::<Day15Solution, _, _, _>(input)
+pub fn solution() -> impl Solution {
+    Linear::<Day15Solution, _, _, _>::new(DayInfo {
+        year: 2024,
+        day: 15,
+        title: "Warehouse Woes",
+        parts: 2,
+    })
 }
 
 #[derive(Clone, Copy, Default, PartialEq)]
@@ -31,13 +38,13 @@ struct State {
     instrs: Vec<Pt<2>>,
 }
 
-fn to_dir(c: char) -> Pt<2> {
+fn to_dir(c: char) -> Option<Pt<2>> {
     match c {
-        '^' => Pt([0, -1]),
-        '>' => Pt([1, 0]),
-        'v' => Pt([0, 1]),
-        '<' => Pt([-1, 0]),
-        _ => panic!("unrecognised instr char"),
+        '^' => Some(Pt([0, -1])),
+        '>' => Some(Pt([1, 0])),
+        'v' => Some(Pt([0, 1])),
+        '<' => Some(Pt([-1, 0])),
+        _ => None,
     }
 }
 
@@ -177,8 +184,12 @@ fn print_grid(state: &State) {
 }
 
 impl SolutionLinear<State, usize, usize> for Day15Solution {
-    fn load(input: &str) -> Result<State> {
-        let (g, is) = input.split_once("\n\n").unwrap();
+    type Params = NoParams;
+
+    fn load(input: &str, _params: &NoParams) -> Result<State> {
+        let (g, is) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::at_end(input, "a blank line after the warehouse"))?;
         let mut bot_pos: Pt<2> = Pt([-1, -1]);
         let mut maze = Grid::default();
 
@@ -200,9 +211,15 @@ impl SolutionLinear<State, usize, usize> for Day15Solution {
         }
 
         let instrs = is
-            .chars()
-            .flat_map(|c| if c == '\n' { None } else { Some(to_dir(c)) })
-            .collect_vec();
+            .lines()
+            .flat_map(|l| {
+                l.char_indices().map(move |(i, c)| {
+                    to_dir(c).ok_or_else(|| {
+                        ParseError::at(input, &l[i..i + c.len_utf8()], "one of '^>v<'")
+                    })
+                })
+            })
+            .collect::<Result<_, _>>()?;
 
         Ok(State {
             g: maze,
@@ -211,7 +228,7 @@ impl SolutionLinear<State, usize, usize> for Day15Solution {
         })
     }
 
-    fn part1(input: &mut State) -> Result<usize> {
+    fn part1(input: &mut State, _params: &NoParams, _progress: &Progress) -> Result<usize> {
         let mut state = input.clone();
         for i in 0..state.instrs.len() {
             simulate(&mut state, i);
@@ -230,7 +247,12 @@ impl SolutionLinear<State, usize, usize> for Day15Solution {
             .sum())
     }
 
-    fn part2(input: &mut State, _part_1_solution: usize) -> Result<usize> {
+    fn part2(
+        input: &mut State,
+        _part_1_solution: usize,
+        _params: &NoParams,
+        _progress: &Progress,
+    ) -> Result<usize> {
         let mut state = expand(input);
         for i in 0..state.instrs.len() {
             simulate(&mut state, i);
@@ -251,43 +273,12 @@ impl SolutionLinear<State, usize, usize> for Day15Solution {
 
 #[cfg(test)]
 mod tests {
-    use super::Day15Solution;
-    use crate::utils::solver_types::SolutionLinear;
+    use crate::runner::examples::assert_example;
     use rstest::rstest;
 
     #[rstest]
-    #[case(
-        "##########
-#..O..O.O#
-#......O.#
-#.OO..O.O#
-#..O@..O.#
-#O#..O...#
-#O..O..O.#
-#.OO.O.OO#
-#....O...#
-##########
-
-<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
-vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
-><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
-<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
-^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
-^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
->^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
-<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
-^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
-v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^",
-        10092,
-        9021
-    )]
-    fn validate_day15(#[case] input: &str, #[case] expected_1: usize, #[case] expected_2: usize) {
-        let mut input = Day15Solution::load(input).unwrap();
-
-        let p1 = Day15Solution::part1(&mut input).unwrap();
-        assert_eq!(expected_1, p1);
-
-        let p2 = Day15Solution::part2(&mut input, p1).unwrap();
-        assert_eq!(expected_2, p2);
+    #[case(1)]
+    fn validate_day15(#[case] example: usize) {
+        assert_example(2024, 15, example);
     }
 }
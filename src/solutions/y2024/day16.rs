@@ -5,15 +5,21 @@ use std::{
 
 use crate::utils::{
     point::Pt,
-    solver_types::{solve_simultaneous, SolutionSimultaneous},
+    progress::Progress,
+    solver_types::{DayInfo, NoParams, PartSink, Simultaneous, Solution, SolutionSimultaneous},
 };
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use itertools::Itertools;
 
 pub struct Day16Solution {}
 
-pub fn day16(input: &str) -> Result<f32> {
-    solve_simultaneous::<Day16Solution, _, _, _>(input)
+pub fn solution() -> impl Solution {
+    Simultaneous::<Day16Solution, _, _, _>::new(DayInfo {
+        year: 2024,
+        day: 16,
+        title: "Reindeer Maze",
+        parts: 2,
+    })
 }
 
 /// E S W N
@@ -67,7 +73,6 @@ fn to_edges(g: HashSet<Pt<2>>) -> Edges {
         }
         edges.insert(**p, paths);
     }
-    println!("{:?}", edges.get(&Pt([1, 139])));
     edges
 }
 
@@ -141,10 +146,12 @@ fn dfs(
     target: &Pt<2>,
     edges: &Edges,
     cached: &mut HashMap<(Pt<2>, usize, usize), Vec<Pt<2>>>,
+    progress: &Progress,
 ) -> Option<Vec<Pt<2>>> {
+    // a cancelled search finds nothing
+    progress.tick().ok()?;
     let pt = *next.last().unwrap();
     if let Some(res) = cached.get(&(pt, bearing, score)) {
-        println!("hit");
         return Some(res.to_vec());
     }
 
@@ -158,7 +165,7 @@ fn dfs(
 
     let mut sub = next_nodes(&pt, bearing, edges, None, score)
         .into_iter()
-        .filter_map(|n| dfs(n, max_score, target, edges, cached))
+        .filter_map(|n| dfs(n, max_score, target, edges, cached, progress))
         .flatten()
         .collect_vec();
 
@@ -173,7 +180,9 @@ fn dfs(
 }
 
 impl SolutionSimultaneous<State, usize, usize> for Day16Solution {
-    fn load(input: &str) -> Result<State> {
+    type Params = NoParams;
+
+    fn load(input: &str, _params: &NoParams) -> Result<State> {
         let mut start = Pt([-1, -1]);
         let mut end = Pt([-1, -1]);
         let mut pts = HashSet::new();
@@ -195,7 +204,12 @@ impl SolutionSimultaneous<State, usize, usize> for Day16Solution {
         Ok(State { start, end, edges })
     }
 
-    fn solve(input: State) -> Result<(usize, usize)> {
+    fn solve(
+        input: State,
+        sink: &mut PartSink<usize, usize>,
+        _params: &NoParams,
+        progress: &Progress,
+    ) -> Result<()> {
         let d = distances(&input.start, &input.edges);
         let mut p1 = usize::MAX;
         for i in 0..4 {
@@ -203,6 +217,12 @@ impl SolutionSimultaneous<State, usize, usize> for Day16Solution {
                 p1 = p1.min(*v);
             }
         }
+        sink.part1(p1);
+
+        // finding every best path is the slow bit
+        if !sink.wants_part2() {
+            return Ok(());
+        }
 
         let visited = dfs(
             (0, 0, vec![input.start]),
@@ -210,43 +230,22 @@ impl SolutionSimultaneous<State, usize, usize> for Day16Solution {
             &input.end,
             &input.edges,
             &mut HashMap::new(),
+            progress,
         )
-        .unwrap();
-        Ok((p1, visited.into_iter().unique().count()))
+        .ok_or_else(|| anyhow!("No best path found"))?;
+        sink.part2(visited.into_iter().unique().count());
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Day16Solution;
-    use crate::utils::solver_types::SolutionSimultaneous;
+    use crate::runner::examples::assert_example;
     use rstest::rstest;
 
     #[rstest]
-    #[case(
-        "###############
-#.......#....E#
-#.#.###.#.###.#
-#.....#.#...#.#
-#.###.#####.#.#
-#.#.#.......#.#
-#.#.#####.###.#
-#...........#.#
-###.#.#####.#.#
-#...#.....#.#.#
-#.#.#.###.#.#.#
-#.....#...#.#.#
-#.###.#.#.#.#.#
-#S..#.....#...#
-###############",
-        7036,
-        45
-    )]
-    fn validate_day16(#[case] input: &str, #[case] expected_1: usize, #[case] expected_2: usize) {
-        let input = Day16Solution::load(input).unwrap();
-
-        let (p1, p2) = Day16Solution::solve(input).unwrap();
-        assert_eq!(expected_1, p1);
-        assert_eq!(expected_2, p2);
+    #[case(1)]
+    fn validate_day16(#[case] example: usize) {
+        assert_example(2024, 16, example);
     }
 }
@@ -1,13 +1,22 @@
 use std::ops::{BitXor, Div};
 
-use crate::utils::solver_types::{solve_linear, SolutionLinear};
+use crate::utils::{
+    load_input::{int, list, map, pair, parse, preceded},
+    progress::Progress,
+    solver_types::{DayInfo, Linear, NoParams, Solution, SolutionLinear},
+};
 use anyhow::Result;
 use itertools::Itertools;
 
 pub struct Day17Solution {}
 
-pub fn day17(input: &str) -> Result<f32> {
-    solve_linear::<Day17Solution, _, _, _>(input)
+pub fn solution() -> impl Solution {
+    Linear::<Day17Solution, _, _, _>::new(DayInfo {
+        year: 2024,
+        day: 17,
+        title: "Chronospatial Computer",
+        parts: 2,
+    })
 }
 
 #[derive(Debug, Clone)]
@@ -126,36 +135,41 @@ fn solve(
 }
 
 impl SolutionLinear<Machine, String, usize> for Day17Solution {
-    fn load(input: &str) -> Result<Machine> {
-        let ls = input.lines().collect_vec();
-        let mut regs = [0; 3];
-        for i in 0..3 {
-            regs[i] = ls[i].split_once(": ").unwrap().1.parse().unwrap();
-        }
-        let program = ls
-            .last()
-            .unwrap()
-            .split_once(' ')
-            .unwrap()
-            .1
-            .split(',')
-            .map(|v| v.parse().unwrap())
-            .collect_vec();
+    type Params = NoParams;
+
+    fn load(input: &str, _params: &NoParams) -> Result<Machine> {
+        let register = |name| preceded(&format!("Register {}: ", name), int());
+        let registers = map(
+            pair(
+                register("A"),
+                "\n",
+                pair(register("B"), "\n", register("C")),
+            ),
+            |(a, (b, c))| [a, b, c],
+        );
+        let program = preceded("Program: ", list(",", int()));
+
+        let (reg, program) = parse(input, pair(registers, "\n\n", program))?;
 
         Ok(Machine {
-            reg: regs,
+            reg,
             ptr: 0,
             program,
             out: vec![],
         })
     }
 
-    fn part1(input: &mut Machine) -> Result<String> {
+    fn part1(input: &mut Machine, _params: &NoParams, _progress: &Progress) -> Result<String> {
         let mut machine = input.clone();
         Ok(machine.run())
     }
 
-    fn part2(input: &mut Machine, _part_1_solution: String) -> Result<usize> {
+    fn part2(
+        input: &mut Machine,
+        _part_1_solution: String,
+        _params: &NoParams,
+        _progress: &Progress,
+    ) -> Result<usize> {
         let mut target = input.program.clone();
         target.reverse();
         let mut x = vec![1; target.len()];
@@ -168,42 +182,13 @@ impl SolutionLinear<Machine, String, usize> for Day17Solution {
 
 #[cfg(test)]
 mod tests {
-    use super::Day17Solution;
-    use crate::utils::solver_types::SolutionLinear;
+    use crate::runner::examples::assert_example;
     use rstest::rstest;
 
     #[rstest]
-    #[case(
-        "Register A: 729
-Register B: 0
-Register C: 0
-
-Program: 0,1,5,4,3,0",
-        "4,6,3,5,6,3,5,2,1,0",
-        None
-    )]
-    #[case(
-        "Register A: 2024
-Register B: 0
-Register C: 0
-
-Program: 0,3,5,4,3,0",
-        "5,7,3,0",
-        Some(117440)
-    )]
-    fn validate_day17(
-        #[case] input: &str,
-        #[case] expected_1: String,
-        #[case] expected_2: Option<usize>,
-    ) {
-        let mut input = Day17Solution::load(input).unwrap();
-
-        let p1 = Day17Solution::part1(&mut input).unwrap();
-        assert_eq!(expected_1, p1);
-
-        if expected_2.is_some() {
-            let p2 = Day17Solution::part2(&mut input, p1).unwrap();
-            assert_eq!(expected_2.unwrap(), p2);
-        }
+    #[case(1)]
+    #[case(2)]
+    fn validate_day17(#[case] example: usize) {
+        assert_example(2024, 17, example);
     }
 }
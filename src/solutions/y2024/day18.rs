@@ -0,0 +1,166 @@
+use std::collections::HashSet;
+
+use crate::utils::{
+    load_input::try_load_lines,
+    parse_error::{parse_int, split_pair},
+    point::Pt,
+    progress::Progress,
+    solver_types::{DayInfo, Linear, Solution, SolutionLinear},
+};
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+pub struct Day18Solution {}
+
+pub fn solution() -> impl Solution {
+    Linear::<Day18Solution, _, _, _>::new(DayInfo {
+        year: 2024,
+        day: 18,
+        title: "RAM Run",
+        parts: 2,
+    })
+}
+
+/// The memory space runs from 0 to `size` on both axes, and part 1 looks at it after `fallen` bytes
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day18Params {
+    size: isize,
+    fallen: usize,
+}
+
+impl Default for Day18Params {
+    fn default() -> Self {
+        Self {
+            size: 70,
+            fallen: 1024,
+        }
+    }
+}
+
+/// bfs to end goal
+fn navigate(dim: isize, bits: &HashSet<Pt<2>>) -> Result<usize> {
+    let target = Pt([dim, dim]);
+    let mut frontier = vec![(Pt([0, 0]), 0_usize)];
+    let mut visited = HashSet::new();
+
+    while !frontier.is_empty() {
+        frontier.sort_by(|(_, a), (_, b)| b.cmp(a));
+        let (next_p, next_c) = frontier.pop().unwrap();
+
+        if visited.contains(&next_p) {
+            continue;
+        }
+
+        visited.insert(next_p);
+        if next_p == target {
+            return Ok(next_c);
+        }
+
+        Pt::<2>::card_offsets().into_iter().for_each(|off| {
+            let Pt([x, y]) = next_p + off;
+            if 0 > x || dim < x || 0 > y || dim < y {
+                return;
+            }
+            if !bits.contains(&Pt([x, y])) {
+                if let Some(idx) = frontier
+                    .iter()
+                    .position(|(p, c)| *p == Pt([x, y]) && *c > next_c + 1)
+                {
+                    frontier.remove(idx);
+                }
+                frontier.push((Pt([x, y]), next_c + 1));
+            }
+        });
+    }
+
+    Err(anyhow!("No path found"))
+}
+
+impl SolutionLinear<Vec<Pt<2>>, usize, String> for Day18Solution {
+    type Params = Day18Params;
+
+    fn load(input: &str, _params: &Day18Params) -> Result<Vec<Pt<2>>> {
+        Ok(try_load_lines(input, |l| {
+            let (x, y) = split_pair(input, l, ",")?;
+            Ok(Pt([parse_int(input, x)?, parse_int(input, y)?]))
+        })?)
+    }
+
+    fn part1(input: &mut Vec<Pt<2>>, params: &Day18Params, _progress: &Progress) -> Result<usize> {
+        let bits_fallen = input.iter().cloned().take(params.fallen).collect();
+
+        navigate(params.size, &bits_fallen)
+    }
+
+    /// solve part 2 with a binary search
+    fn part2(
+        input: &mut Vec<Pt<2>>,
+        _part_1_solution: usize,
+        params: &Day18Params,
+        progress: &Progress,
+    ) -> Result<String> {
+        let (dim, bits) = (params.size, params.fallen);
+
+        let mut l = bits.min(input.len());
+        let mut r = input.len();
+        let mut idx;
+
+        // each step halves the range
+        progress.start((r - l).max(1).ilog2() as usize + 1);
+        loop {
+            progress.tick()?;
+            idx = (l + r) / 2;
+            match navigate(dim, &input.iter().cloned().take(idx).collect()) {
+                Ok(_) => {
+                    if navigate(dim, &input.iter().cloned().take(idx + 1).collect()).is_err() {
+                        break;
+                    }
+                    // the search never stops if every byte can fall without cutting off the exit
+                    if idx + 1 >= input.len() {
+                        return Err(anyhow!("The exit is never cut off"));
+                    }
+                    l = idx;
+                }
+                Err(_) => {
+                    if navigate(dim, &input.iter().cloned().take(idx - 1).collect()).is_ok() {
+                        idx -= 1;
+                        break;
+                    }
+                    r = idx
+                }
+            }
+        }
+
+        let Pt([x, y]) = input[idx];
+        Ok(format!("{},{}", x, y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        runner::examples::assert_example,
+        utils::solver_types::{Parts, RawParams, Solution, SolveOptions},
+    };
+    use rstest::rstest;
+
+    use super::solution;
+
+    #[rstest]
+    #[case(1)]
+    fn validate_day18(#[case] example: usize) {
+        assert_example(2024, 18, example);
+    }
+
+    #[test]
+    fn test_exit_never_cut_off() {
+        let options = SolveOptions {
+            parts: Parts::Part2,
+            params: toml::from_str::<RawParams>("size = 6\nfallen = 1").unwrap(),
+            ..SolveOptions::default()
+        };
+
+        let err = solution().solve("1,1\n2,2\n3,3\n", &options).unwrap_err();
+        assert_eq!("The exit is never cut off", err.to_string());
+    }
+}
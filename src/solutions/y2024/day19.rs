@@ -1,12 +1,21 @@
 use std::collections::HashMap;
 
-use crate::utils::solver_types::{solve_simultaneous, SolutionSimultaneous};
+use crate::utils::{
+    parse_error::ParseError,
+    progress::Progress,
+    solver_types::{DayInfo, NoParams, PartSink, Simultaneous, Solution, SolutionSimultaneous},
+};
 use anyhow::Result;
 
 pub struct Day19Solution {}
 
-pub fn day19(input: &str) -> Result<f32> {
-    solve_simultaneous::<Day19Solution, _, _, _>(input)
+pub fn solution() -> impl Solution {
+    Simultaneous::<Day19Solution, _, _, _>::new(DayInfo {
+        year: 2024,
+        day: 19,
+        title: "Linen Layout",
+        parts: 2,
+    })
 }
 
 struct Towels {
@@ -62,8 +71,12 @@ fn valid(
 }
 
 impl SolutionSimultaneous<Towels, usize, usize> for Day19Solution {
-    fn load(input: &str) -> Result<Towels> {
-        let (a, p) = input.split_once("\n\n").unwrap();
+    type Params = NoParams;
+
+    fn load(input: &str, _params: &NoParams) -> Result<Towels> {
+        let (a, p) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::at_end(input, "a blank line after the towels"))?;
         let available = a.split(", ").map(|t| t.chars().collect()).collect();
         let patterns = p.lines().map(|l| l.chars().collect()).collect();
 
@@ -73,43 +86,32 @@ impl SolutionSimultaneous<Towels, usize, usize> for Day19Solution {
         })
     }
 
-    fn solve(input: Towels) -> Result<(usize, usize)> {
+    fn solve(
+        input: Towels,
+        sink: &mut PartSink<usize, usize>,
+        _params: &NoParams,
+        _progress: &Progress,
+    ) -> Result<()> {
         let mut cache = HashMap::new();
         let perms: Vec<usize> = input
             .patterns
             .iter()
             .filter_map(|p| valid(&input.available, &mut cache, p))
             .collect();
-        Ok((perms.len(), perms.iter().sum()))
+        sink.part1(perms.len());
+        sink.part2(perms.iter().sum());
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Day19Solution;
-    use crate::utils::solver_types::SolutionSimultaneous;
+    use crate::runner::examples::assert_example;
     use rstest::rstest;
 
     #[rstest]
-    #[case(
-        "r, wr, b, g, bwu, rb, gb, br
-
-brwrr
-bggr
-gbbr
-rrbgbr
-ubwu
-bwurrg
-brgr
-bbrgwb",
-        6,
-        16
-    )]
-    fn validate_day19(#[case] input: &str, #[case] expected_1: usize, #[case] expected_2: usize) {
-        let input = Day19Solution::load(input).unwrap();
-
-        let (p1, p2) = Day19Solution::solve(input).unwrap();
-        assert_eq!(expected_1, p1);
-        assert_eq!(expected_2, p2);
+    #[case(1)]
+    fn validate_day19(#[case] example: usize) {
+        assert_example(2024, 19, example);
     }
 }
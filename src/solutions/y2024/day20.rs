@@ -2,21 +2,49 @@ use std::collections::{HashMap, HashSet};
 
 use crate::utils::{
     point::Pt,
-    solver_types::{solve_linear, SolutionLinear},
+    progress::Progress,
+    solver_types::{DayInfo, Linear, Solution, SolutionLinear},
 };
 use anyhow::Result;
 use itertools::Itertools;
+use serde::Deserialize;
 
 pub struct Day20Solution {}
 
-pub fn day20(input: &str) -> Result<f32> {
-    solve_linear::<Day20Solution, _, _, _>(input)
+pub fn solution() -> impl Solution {
+    Linear::<Day20Solution, _, _, _>::new(DayInfo {
+        year: 2024,
+        day: 20,
+        title: "Race Condition",
+        parts: 2,
+    })
 }
 
 type Maze = HashSet<Pt<2>>;
 type Distances = HashMap<Pt<2>, usize>;
+/// distances to the end, start, end
 type Input = (Distances, Pt<2>, Pt<2>);
 type Cheat = (Pt<2>, Pt<2>, usize);
+
+/// The least time a cheat must save to be counted, and how long cheats can last in each part
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day20Params {
+    min_saving: usize,
+    part1_cheat: isize,
+    part2_cheat: isize,
+}
+
+impl Default for Day20Params {
+    fn default() -> Self {
+        Self {
+            min_saving: 100,
+            part1_cheat: 2,
+            part2_cheat: 20,
+        }
+    }
+}
+
 /// NESW
 const DIRS: [Pt<2>; 4] = [Pt([0, -1]), Pt([1, 0]), Pt([0, 1]), Pt([-1, 0])];
 
@@ -90,7 +118,9 @@ fn faster_by(
 }
 
 impl SolutionLinear<Input, usize, usize> for Day20Solution {
-    fn load(input: &str) -> Result<Input> {
+    type Params = Day20Params;
+
+    fn load(input: &str, _params: &Day20Params) -> Result<Input> {
         let mut m = HashSet::new();
         let mut s = Pt([-1, -1]);
         let mut e = Pt([-1, -1]);
@@ -113,76 +143,48 @@ impl SolutionLinear<Input, usize, usize> for Day20Solution {
         Ok((d, s, e))
     }
 
-    fn part1((distances, start, _end): &mut Input) -> Result<usize> {
-        let is_test = distances.len() < 225;
-
+    fn part1(
+        (distances, start, _end): &mut Input,
+        params: &Day20Params,
+        _progress: &Progress,
+    ) -> Result<usize> {
         let fastest_time = *distances.get(start).unwrap();
 
         let cheats = distances
             .keys()
-            .flat_map(|p| cheatable_n(distances, p, 2))
+            .flat_map(|p| cheatable_n(distances, p, params.part1_cheat))
             .filter_map(|cheat| faster_by(distances, fastest_time, &cheat))
             .collect_vec();
 
-        if is_test {
-            Ok(cheats.len())
-        } else {
-            Ok(cheats.iter().filter(|v| **v >= 100).count())
-        }
+        Ok(cheats.iter().filter(|v| **v >= params.min_saving).count())
     }
 
-    fn part2((distances, start, _end): &mut Input, _part_1_solution: usize) -> Result<usize> {
-        let is_test = distances.len() < 225;
-
+    fn part2(
+        (distances, start, _end): &mut Input,
+        _part_1_solution: usize,
+        params: &Day20Params,
+        _progress: &Progress,
+    ) -> Result<usize> {
         let fastest_time = *distances.get(start).unwrap();
 
         let cheats = distances
             .keys()
-            .flat_map(|p| cheatable_n(distances, p, 20))
+            .flat_map(|p| cheatable_n(distances, p, params.part2_cheat))
             .filter_map(|cheat| faster_by(distances, fastest_time, &cheat))
             .collect_vec();
 
-        if is_test {
-            Ok(cheats.len())
-        } else {
-            Ok(cheats.iter().filter(|v| **v >= 100).count())
-        }
+        Ok(cheats.iter().filter(|v| **v >= params.min_saving).count())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Day20Solution;
-    use crate::utils::solver_types::SolutionLinear;
+    use crate::runner::examples::assert_example;
     use rstest::rstest;
 
     #[rstest]
-    #[case(
-        "###############
-#...#...#.....#
-#.#.#.#.#.###.#
-#S#...#.#.#...#
-#######.#.#.###
-#######.#.#...#
-#######.#.###.#
-###..E#...#...#
-###.#######.###
-#...###...#...#
-#.#####.#.###.#
-#.#...#.#.#...#
-#.#.#.#.#.#.###
-#...#...#...###
-###############",
-        44,
-        3081
-    )]
-    fn validate_day20(#[case] input: &str, #[case] expected_1: usize, #[case] expected_2: usize) {
-        let mut input = Day20Solution::load(input).unwrap();
-
-        let p1 = Day20Solution::part1(&mut input).unwrap();
-        assert_eq!(expected_1, p1);
-
-        let p2 = Day20Solution::part2(&mut input, p1).unwrap();
-        assert_eq!(expected_2, p2);
+    #[case(1)]
+    fn validate_day20(#[case] example: usize) {
+        assert_example(2024, 20, example);
     }
 }
@@ -0,0 +1,46 @@
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+
+use super::Registry;
+
+/// Add this year's solutions to the registry
+pub fn register(registry: &mut Registry) {
+    registry.register(day01::solution());
+    registry.register(day02::solution());
+    registry.register(day03::solution());
+    registry.register(day04::solution());
+    registry.register(day05::solution());
+    registry.register(day06::solution());
+    registry.register(day07::solution());
+    registry.register(day08::solution());
+    registry.register(day09::solution());
+    registry.register(day10::solution());
+    registry.register(day11::solution());
+    registry.register(day12::solution());
+    registry.register(day13::solution());
+    registry.register(day14::solution());
+    registry.register(day15::solution());
+    registry.register(day16::solution());
+    registry.register(day17::solution());
+    registry.register(day18::solution());
+    registry.register(day19::solution());
+    registry.register(day20::solution());
+}
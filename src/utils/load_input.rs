@@ -1,8 +1,11 @@
-use super::grid::Grid;
+//! Helper functions for loading common input styles
 
-/**
-Helper functions for loading common input styles
-*/
+use std::str::FromStr;
+
+use super::{
+    grid::Grid,
+    parse_error::{parse_int, split_pair, ParseError},
+};
 
 /// Load a string representation of a 2D grid into a Grid object
 /// NOTE: We always assume the top-left elemnt is 0,0, and that x increases rightward and y increases downward
@@ -17,11 +20,37 @@ pub fn load_2d_grid<T: Copy + Default>(input: &str, to_value: fn(char) -> T) ->
     Grid::from(pairs)
 }
 
+/// Load a 2D grid like `load_2d_grid`, failing on any character `to_value` doesn't recognise
+pub fn try_load_2d_grid<T: Copy + Default>(
+    input: &str,
+    expected: &str,
+    to_value: fn(char) -> Option<T>,
+) -> Result<Grid<T, 2>, ParseError> {
+    let mut pairs = vec![];
+    for (y, line) in (0..).zip(input.split('\n')) {
+        for (x, (i, c)) in (0..).zip(line.char_indices()) {
+            let value = to_value(c)
+                .ok_or_else(|| ParseError::at(input, &line[i..i + c.len_utf8()], expected))?;
+            pairs.push((vec![x, y], value));
+        }
+    }
+
+    Ok(Grid::from(pairs))
+}
+
 /// load values from an \n-seperated list
 pub fn load_lines<T>(input: &str, to_value: fn(&str) -> T) -> Vec<T> {
     input.lines().map(to_value).collect()
 }
 
+/// load values from an \n-seperated list, stopping at the first that can't be parsed
+pub fn try_load_lines<T>(
+    input: &str,
+    to_value: impl Fn(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input.lines().map(to_value).collect()
+}
+
 /// load values from a list of \n-seperated list
 pub fn load_segmented_lines<T>(
     input: &str,
@@ -34,9 +63,149 @@ pub fn load_segmented_lines<T>(
         .collect()
 }
 
+/// The slices of some text that are integers, with the `-` in front of them if they can be negative
+struct IntSlices<'a> {
+    text: &'a str,
+    at: usize,
+    signed: bool,
+}
+
+impl<'a> IntSlices<'a> {
+    fn new(text: &'a str, signed: bool) -> Self {
+        IntSlices {
+            text,
+            at: 0,
+            signed,
+        }
+    }
+}
+
+impl<'a> Iterator for IntSlices<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let bytes = self.text.as_bytes();
+        let start = self.at + bytes[self.at..].iter().position(u8::is_ascii_digit)?;
+        let end = bytes[start..]
+            .iter()
+            .position(|b| !b.is_ascii_digit())
+            .map_or(bytes.len(), |len| start + len);
+        self.at = end;
+
+        if self.signed && start > 0 && bytes[start - 1] == b'-' {
+            Some(&self.text[start - 1..end])
+        } else {
+            Some(&self.text[start..end])
+        }
+    }
+}
+
+/// whether a `T` can be negative, so a `-` in front of a number is its sign rather than a separator
+fn is_signed<T: FromStr>() -> bool {
+    "-1".parse::<T>().is_ok()
+}
+
+/// Every integer in `text`, whatever's around them, e.g. `94` and `-34` from `Button A: X+94, Y-34`.
+/// A `-` is only taken as a sign when `T` can be negative, and numbers too big for a `T` are skipped
+pub fn ints<T: FromStr>(text: &str) -> impl Iterator<Item = T> + '_ {
+    IntSlices::new(text, is_signed::<T>()).filter_map(|i| i.parse().ok())
+}
+
+/// Exactly `N` integers from `text`, a slice of `input`, like `ints`. Too many, too few, or one too big
+/// for a `T` is an error
+pub fn ints_n<T: FromStr, const N: usize>(input: &str, text: &str) -> Result<[T; N], ParseError> {
+    let found = IntSlices::new(text, is_signed::<T>())
+        .map(|i| parse_int(input, i))
+        .collect::<Result<Vec<T>, _>>()?;
+
+    found
+        .try_into()
+        .map_err(|_| ParseError::at(input, text, &format!("{} integers", N)))
+}
+
+/// Parses a slice of an input, which it's given too so it can say where anything went wrong.
+/// Build one from the functions below and run it with `parse`, e.g. `lines(pair(int(), ": ", list(" ", int())))`
+/// for lines like `190: 10 19`
+pub trait Parser<T>: Fn(&str, &str) -> Result<T, ParseError> {}
+
+impl<T, F: Fn(&str, &str) -> Result<T, ParseError>> Parser<T> for F {}
+
+/// Parse the whole of `input`
+pub fn parse<T>(input: &str, parser: impl Parser<T>) -> Result<T, ParseError> {
+    parser(input, input)
+}
+
+/// an integer, ignoring any whitespace around it
+pub fn int<T: FromStr>() -> impl Parser<T> {
+    |input: &str, text: &str| parse_int(input, text.trim())
+}
+
+/// every integer in the text, ignoring everything between them. See `ints`
+pub fn all_ints<T: FromStr>() -> impl Parser<Vec<T>> {
+    |input: &str, text: &str| {
+        IntSlices::new(text, is_signed::<T>())
+            .map(|i| parse_int(input, i))
+            .collect()
+    }
+}
+
+/// the text with `prefix` taken off the front, parsed by `parser`
+pub fn preceded<T>(prefix: &str, parser: impl Parser<T>) -> impl Parser<T> {
+    let prefix = prefix.to_string();
+    move |input: &str, text: &str| {
+        let rest = text
+            .strip_prefix(&prefix)
+            .ok_or_else(|| ParseError::at(input, text, &format!("'{}'", prefix.escape_debug())))?;
+        parser(input, rest)
+    }
+}
+
+/// a named value like `p=0,4`
+pub fn field<T>(name: &str, parser: impl Parser<T>) -> impl Parser<T> {
+    preceded(&format!("{}=", name), parser)
+}
+
+/// two values either side of the first `delimiter`, like `47|53`
+pub fn pair<A, B>(
+    first: impl Parser<A>,
+    delimiter: &str,
+    second: impl Parser<B>,
+) -> impl Parser<(A, B)> {
+    let delimiter = delimiter.to_string();
+    move |input: &str, text: &str| {
+        let (a, b) = split_pair(input, text, &delimiter)?;
+        Ok((first(input, a)?, second(input, b)?))
+    }
+}
+
+/// values separated by `delimiter`, like `75,47,61`
+pub fn list<T>(delimiter: &str, item: impl Parser<T>) -> impl Parser<Vec<T>> {
+    let delimiter = delimiter.to_string();
+    move |input: &str, text: &str| text.split(&delimiter).map(|i| item(input, i)).collect()
+}
+
+/// a value from each line
+pub fn lines<T>(item: impl Parser<T>) -> impl Parser<Vec<T>> {
+    move |input: &str, text: &str| text.lines().map(|l| item(input, l)).collect()
+}
+
+/// turn what `parser` finds into something else
+pub fn map<A, B>(parser: impl Parser<A>, f: impl Fn(A) -> B) -> impl Parser<B> {
+    move |input: &str, text: &str| parser(input, text).map(&f)
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::utils::{grid::Grid, load_input::load_2d_grid, point::Pt};
+    use rstest::rstest;
+
+    use crate::utils::{
+        grid::Grid,
+        load_input::{
+            all_ints, field, int, ints, ints_n, lines, list, load_2d_grid, map, pair, parse,
+            preceded,
+        },
+        point::Pt,
+    };
 
     #[test]
     fn test_load_2d_grid() {
@@ -63,4 +232,84 @@ mod tests {
 
         assert_eq!(expected.grid, result.grid);
     }
+
+    #[test]
+    fn test_parsers() {
+        assert_eq!(
+            Ok(vec![(190, vec![10, 19]), (3267, vec![81, 40, 27])]),
+            parse(
+                "190: 10 19\n3267: 81 40 27\n",
+                lines(pair(int::<usize>(), ": ", list(" ", int())))
+            )
+        );
+        assert_eq!(
+            Ok(((47, 53), vec![75, 47])),
+            parse(
+                "47|53\n\n75,47",
+                pair(pair(int::<usize>(), "|", int()), "\n\n", list(",", int()))
+            )
+        );
+        assert_eq!(
+            Ok(Pt([3, -3])),
+            parse(
+                "v=3,-3",
+                map(field("v", pair(int(), ",", int())), |(x, y)| Pt([x, y]))
+            )
+        );
+        assert_eq!(
+            Ok(vec![94, -34, 8400]),
+            parse("Button A: X+94, Y-34 at 8400", all_ints::<isize>())
+        );
+        assert_eq!(
+            Ok(729),
+            parse("Register A: 729", preceded("Register A: ", int::<usize>()))
+        );
+    }
+
+    #[rstest]
+    #[case("1|2\n3-4\n", "line 2, col 1: expected '|', found '3-4'")]
+    #[case("1|2\n3|x4\n", "line 2, col 3: expected integer, found 'x4'")]
+    #[case("1|2\n3|4\n|5", "line 3, col 1: expected integer, found nothing")]
+    fn validate_parser_errors(#[case] input: &str, #[case] expected: &str) {
+        let err = parse(input, lines(pair(int::<usize>(), "|", int::<usize>()))).unwrap_err();
+
+        assert_eq!(expected, err.to_string());
+    }
+
+    #[rstest]
+    #[case("Button A: X+94, Y-34", vec![94, -34])]
+    #[case("p=0,4 v=3,-3", vec![0, 4, 3, -3])]
+    #[case("5-3 --2 -", vec![5, -3, -2])]
+    #[case("123", vec![123])]
+    #[case("no numbers", vec![])]
+    fn validate_ints(#[case] text: &str, #[case] expected: Vec<isize>) {
+        assert_eq!(expected, ints::<isize>(text).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_ints() {
+        // a - isn't a sign when the numbers can't be negative
+        assert_eq!(vec![1, 5], ints::<usize>("1-5").collect::<Vec<_>>());
+        // 300 doesn't fit
+        assert_eq!(vec![1, 2], ints::<u8>("1 300 2").collect::<Vec<_>>());
+
+        assert_eq!(
+            Ok([94, -34]),
+            ints_n::<isize, 2>("X+94, Y-34", "X+94, Y-34")
+        );
+    }
+
+    #[rstest]
+    #[case("1,2\n3,4,5", "line 2, col 1: expected 2 integers, found '3,4,5'")]
+    #[case("1,2\n3", "line 2, col 1: expected 2 integers, found '3'")]
+    #[case("1,2\n3,300", "line 2, col 3: expected integer, found '300'")]
+    fn validate_ints_n_errors(#[case] input: &str, #[case] expected: &str) {
+        let err = parse(
+            input,
+            lines(|input: &str, l: &str| ints_n::<u8, 2>(input, l)),
+        )
+        .unwrap_err();
+
+        assert_eq!(expected, err.to_string());
+    }
 }
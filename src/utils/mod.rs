@@ -1,5 +1,7 @@
 pub mod grid;
 pub mod load_input;
 pub mod maths;
+pub mod parse_error;
 pub mod point;
+pub mod progress;
 pub mod solver_types;
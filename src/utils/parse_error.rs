@@ -0,0 +1,138 @@
+//! Errors for puzzle inputs that couldn't be loaded
+
+use std::{error::Error, fmt::Display, str::FromStr};
+
+/// Where and why an input couldn't be loaded. Lines and columns count from 1,
+/// and a line of 0 means we couldn't tell where the text came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub col: usize,
+    /// the offending text
+    pub text: String,
+    /// what we wanted instead, e.g. `integer`
+    pub expected: String,
+}
+
+impl ParseError {
+    /// An error about `text`, which should be a slice of `input` (e.g. from `lines()`, `split()` or `trim()`)
+    /// so it can be found again
+    pub fn at(input: &str, text: &str, expected: &str) -> Self {
+        let (line, col) = locate(input, text).unwrap_or((0, 0));
+        ParseError {
+            line,
+            col,
+            text: text.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+
+    /// An error about something missing from the end of `input`
+    pub fn at_end(input: &str, expected: &str) -> Self {
+        ParseError::at(input, &input[input.len()..], expected)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.line > 0 {
+            write!(f, "line {}, col {}: ", self.line, self.col)?;
+        }
+        write!(f, "expected {}", self.expected)?;
+        // a whole section of the input is too much to show
+        match self.text.lines().next() {
+            None => write!(f, ", found nothing"),
+            Some(first) if first.len() < self.text.trim_end().len() => {
+                write!(f, ", found '{}...'", first)
+            }
+            Some(first) => write!(f, ", found '{}'", first),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// find the line and column `text` starts at, if it's a slice of `input`
+fn locate(input: &str, text: &str) -> Option<(usize, usize)> {
+    let offset = (text.as_ptr() as usize).checked_sub(input.as_ptr() as usize)?;
+    if offset + text.len() > input.len() {
+        return None;
+    }
+
+    let before = &input[..offset];
+    let line = before.matches('\n').count() + 1;
+    let col = before.chars().rev().take_while(|c| *c != '\n').count() + 1;
+    Some((line, col))
+}
+
+/// Parse `text`, a slice of `input`, as a `T`
+pub fn parse_as<T: FromStr>(input: &str, text: &str, expected: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::at(input, text, expected))
+}
+
+/// Parse `text`, a slice of `input`, as an integer
+pub fn parse_int<T: FromStr>(input: &str, text: &str) -> Result<T, ParseError> {
+    parse_as(input, text, "integer")
+}
+
+/// Split `text`, a slice of `input`, around the first `delimiter`
+pub fn split_pair<'a>(
+    input: &str,
+    text: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::at(input, text, &format!("'{}'", delimiter.escape_debug())))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{parse_int, split_pair, ParseError};
+
+    #[rstest]
+    #[case("12\n3x\n", 1, 1, 1, "line 2, col 2: expected integer, found 'x'")]
+    #[case("12 34", 0, 2, 1, "line 1, col 3: expected integer, found ' '")]
+    #[case("ab\né7\n", 1, 2, 1, "line 2, col 2: expected integer, found '7'")]
+    fn validate_parse_error(
+        #[case] input: &str,
+        #[case] line: usize,
+        #[case] start: usize,
+        #[case] len: usize,
+        #[case] expected: &str,
+    ) {
+        let l = input.lines().nth(line).unwrap();
+        let text = &l[start..start + len];
+        let err = ParseError::at(input, text, "integer");
+
+        assert_eq!(expected, err.to_string());
+    }
+
+    #[test]
+    fn test_helpers() {
+        let input = "1,2\n3;4";
+        let second = input.lines().nth(1).unwrap();
+
+        assert_eq!(("1", "2"), split_pair(input, "1,2", ",").unwrap());
+        assert_eq!(Ok(12), parse_int::<usize>(input, "12"));
+        assert_eq!(
+            "line 2, col 1: expected ',', found '3;4'",
+            split_pair(input, second, ",").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 2, col 4: expected 'Program', found nothing",
+            ParseError::at_end(input, "'Program'").to_string()
+        );
+        assert_eq!(
+            "line 1, col 1: expected '\\n\\n', found '1,2...'",
+            split_pair(input, input, "\n\n").unwrap_err().to_string()
+        );
+        // text from elsewhere can't be located
+        assert_eq!(
+            "expected integer, found 'x'",
+            parse_int::<usize>(input, "x").unwrap_err().to_string()
+        );
+    }
+}
@@ -0,0 +1,274 @@
+//! Progress of long-running solutions, shown as a live line on stderr
+
+use std::{
+    io::{self, IsTerminal, Write},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Instant,
+};
+
+use anyhow::{anyhow, Result};
+
+/// How often the progress line is redrawn, in milliseconds. Nothing is drawn for anything quicker
+const REDRAW_EVERY_MS: u64 = 100;
+
+/// What a solution's progress shares with whatever's waiting on it
+#[derive(Debug, Default)]
+struct Shared {
+    cancelled: AtomicBool,
+    /// whether there's a line on stderr to clear
+    drawn: AtomicBool,
+}
+
+/// Tells a solution's [`Progress`] to stop, e.g. once the solution's timed out and nobody's waiting for its answer.
+/// Clones share the same flag, so one can be kept to cancel whichever solution is given the other
+#[derive(Debug, Clone, Default)]
+pub struct Cancel(Arc<Shared>);
+
+impl Cancel {
+    /// stop the progress line, clearing it if it's been drawn, and make every tick from now on fail
+    pub fn cancel(&self) {
+        // progress is only drawn with stderr locked, so nothing can be drawn once this has cleared the line
+        let mut stderr = io::stderr().lock();
+        self.0.cancelled.store(true, Ordering::Relaxed);
+        if self.0.drawn.swap(false, Ordering::Relaxed) {
+            let _ = write!(stderr, "\r\x1b[K");
+            let _ = stderr.flush();
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// Clones are the same flag
+impl PartialEq for Cancel {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Passed to every part of a solution so it can say how far it's got, e.g. `progress.start(positions.len())`
+/// then `progress.tick()?` for each position tried. It's cheap to tick often: a silent handle only checks whether
+/// the solution's been cancelled, and a live one only checks the clock too until it's time to redraw
+#[derive(Debug)]
+pub struct Progress {
+    live: bool,
+    done: AtomicUsize,
+    total: AtomicUsize,
+    /// what's being counted, e.g. `Part 2`
+    label: Mutex<String>,
+    /// when the handle was made, which the redraw deadline counts from
+    epoch: Instant,
+    /// milliseconds after `epoch` when the line's next due to be drawn, or `u64::MAX` before anything's begun
+    redraw_at: AtomicU64,
+    cancel: Cancel,
+}
+
+impl Progress {
+    /// a handle that never shows anything, e.g. for tests or JSON output
+    pub fn silent() -> Self {
+        Self::new(false)
+    }
+
+    /// a handle that draws a progress line on stderr, if stderr is a terminal
+    pub fn live() -> Self {
+        Self::new(io::stderr().is_terminal())
+    }
+
+    fn new(live: bool) -> Self {
+        Self {
+            live,
+            done: AtomicUsize::new(0),
+            total: AtomicUsize::new(0),
+            label: Mutex::default(),
+            epoch: Instant::now(),
+            redraw_at: AtomicU64::new(u64::MAX),
+            cancel: Cancel::default(),
+        }
+    }
+
+    /// stop when `cancel` is cancelled
+    pub fn cancelled_by(mut self, cancel: &Cancel) -> Self {
+        self.cancel = cancel.clone();
+        self
+    }
+
+    /// whether anything is being shown
+    pub fn is_live(&self) -> bool {
+        self.live
+    }
+
+    /// start counting from 0 towards an estimated `total`, or 0 if there's no telling
+    pub fn start(&self, total: usize) {
+        self.done.store(0, Ordering::Relaxed);
+        self.total.store(total, Ordering::Relaxed);
+    }
+
+    /// count one more done. Fails once the solution's been cancelled, so it can give up early with `?`
+    pub fn tick(&self) -> Result<()> {
+        self.add(1)
+    }
+
+    /// count `n` more done. Fails once the solution's been cancelled
+    pub fn add(&self, n: usize) -> Result<()> {
+        if self.cancel.is_cancelled() {
+            return Err(anyhow!("Cancelled"));
+        }
+        if !self.live {
+            return Ok(());
+        }
+        let done = self.done.fetch_add(n, Ordering::Relaxed) + n;
+
+        let now = self.now_ms();
+        let redraw_at = self.redraw_at.load(Ordering::Relaxed);
+        // only one tick gets to redraw each time it's due
+        if now < redraw_at
+            || self
+                .redraw_at
+                .compare_exchange(
+                    redraw_at,
+                    now + REDRAW_EVERY_MS,
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                )
+                .is_err()
+        {
+            return Ok(());
+        }
+
+        let label = self.label.lock().unwrap();
+        self.draw(&format_line(
+            &label,
+            done,
+            self.total.load(Ordering::Relaxed),
+        ));
+        Ok(())
+    }
+
+    /// what the line says it's counting, e.g. `Part 2`. Starts a new count with no total
+    pub(crate) fn begin(&self, label: &str) {
+        self.start(0);
+        *self.label.lock().unwrap() = label.to_string();
+        self.redraw_later();
+    }
+
+    /// clear the line, if anything was drawn, so other output can take its place.
+    /// It's only drawn again once whatever's left has run for a while too
+    pub(crate) fn finish(&self) {
+        self.redraw_later();
+        if self.cancel.0.drawn.load(Ordering::Relaxed) {
+            self.draw("");
+        }
+    }
+
+    /// put off the next redraw for a while
+    fn redraw_later(&self) {
+        self.redraw_at
+            .store(self.now_ms() + REDRAW_EVERY_MS, Ordering::Relaxed);
+    }
+
+    /// milliseconds since the handle was made
+    fn now_ms(&self) -> u64 {
+        self.epoch.elapsed().as_millis() as u64
+    }
+
+    /// replace the current line of stderr, unless the solution's been cancelled and the line's gone
+    fn draw(&self, text: &str) {
+        let mut stderr = io::stderr().lock();
+        if self.cancel.is_cancelled() {
+            return;
+        }
+        self.cancel
+            .0
+            .drawn
+            .store(!text.is_empty(), Ordering::Relaxed);
+        // progress is only a nicety, so a failed write isn't worth stopping for
+        let _ = write!(stderr, "\r\x1b[K{}", text);
+        let _ = stderr.flush();
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
+
+/// e.g. `Part 2: 1200/5000 (24%)`, or `Part 2: 1200` without a total
+fn format_line(label: &str, done: usize, total: usize) -> String {
+    if total == 0 {
+        format!("{}: {}", label, done)
+    } else {
+        format!(
+            "{}: {}/{} ({}%)",
+            label,
+            done,
+            total,
+            // wide enough that no count can overflow
+            (done as u128 * 100 / total as u128).min(100)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::Ordering;
+
+    use rstest::rstest;
+
+    use super::{format_line, Cancel, Progress};
+
+    #[rstest]
+    #[case("Part 2", 1200, 5000, "Part 2: 1200/5000 (24%)")]
+    #[case("Part 1", 12, 0, "Part 1: 12")]
+    #[case("Part 1", 7, 5, "Part 1: 7/5 (100%)")]
+    #[case("Part 1", usize::MAX, 2, &format!("Part 1: {}/2 (100%)", usize::MAX))]
+    fn validate_format_line(
+        #[case] label: &str,
+        #[case] done: usize,
+        #[case] total: usize,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(expected, format_line(label, done, total));
+    }
+
+    #[test]
+    fn test_silent() {
+        let progress = Progress::silent();
+        progress.start(10);
+        progress.tick().unwrap();
+
+        assert!(!progress.is_live());
+        // nothing is counted when nothing is shown
+        assert_eq!(0, progress.done.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn test_quick_ticks_draw_nothing() {
+        let progress = Progress::new(true);
+        progress.begin("Part 1");
+        progress.start(1000);
+        for _ in 0..1000 {
+            progress.tick().unwrap();
+        }
+
+        assert_eq!(1000, progress.done.load(Ordering::Relaxed));
+        assert!(!progress.cancel.0.drawn.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn test_cancel() {
+        let cancel = Cancel::default();
+        let progress = Progress::silent().cancelled_by(&cancel);
+        assert!(progress.tick().is_ok());
+
+        cancel.cancel();
+        assert!(progress.tick().is_err());
+        assert!(progress.add(5).is_err());
+        assert_eq!(cancel, progress.cancel);
+    }
+}
@@ -1,86 +1,736 @@
-use anyhow::Result;
-use std::{fmt::Display, time::Instant};
+use anyhow::{anyhow, Context, Result};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use super::progress::{Cancel, Progress};
+use std::{fmt::Display, marker::PhantomData, time::Instant};
+
+/// Which style of solver produced a report
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SolverKind {
+    Linear,
+    Simultaneous,
+}
+
+impl Display for SolverKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolverKind::Linear => write!(f, "linear"),
+            SolverKind::Simultaneous => write!(f, "simultaneous"),
+        }
+    }
+}
+
+/// Which parts of a day to solve
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Parts {
+    #[default]
+    Both,
+    Part1,
+    /// Linear solutions still have to solve part 1 first, but only part 2's answer is reported
+    Part2,
+}
+
+impl Parts {
+    /// `None` for both parts, otherwise just the given part
+    pub fn only(part: Option<u8>) -> Self {
+        match part {
+            None => Parts::Both,
+            Some(1) => Parts::Part1,
+            Some(_) => Parts::Part2,
+        }
+    }
+
+    pub fn part1(&self) -> bool {
+        *self != Parts::Part2
+    }
+
+    pub fn part2(&self) -> bool {
+        *self != Parts::Part1
+    }
+}
+
+/// `key = value` overrides for a day's parameters, e.g. from `--param size=6` or an example's `params` table
+pub type RawParams = toml::Table;
+
+/// How to solve a day
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SolveOptions {
+    pub parts: Parts,
+    /// overrides for the day's `Params`. Anything not given keeps its default
+    pub params: RawParams,
+    /// show a live progress line on stderr while solving, if it's a terminal
+    pub progress: bool,
+    /// stops the solution's progress, and the solution if it checks, e.g. once it's timed out
+    pub cancel: Cancel,
+}
+
+impl SolveOptions {
+    /// solve the given parts with default parameters
+    pub fn new(parts: Parts) -> Self {
+        Self {
+            parts,
+            params: RawParams::new(),
+            progress: false,
+            cancel: Cancel::default(),
+        }
+    }
+}
+
+/// The `Params` of a day without any
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NoParams {}
+
+/// Fill in a day's parameters from overrides, e.g. turning `{ size = 6 }` into `Day18Params { size: 6, fallen: 1024 }`.
+/// Params structs should use `#[serde(default, deny_unknown_fields)]`, so missing keys keep their defaults and typos are caught
+pub fn parse_params<P: DeserializeOwned>(raw: &RawParams) -> Result<P> {
+    toml::Value::Table(raw.clone())
+        .try_into()
+        .context("Invalid parameters for this day")
+}
+
+/// A `--param` override, for a single day if it's given as e.g. `11.blinks=40`, otherwise for every day that's run
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParamOverride {
+    pub day: Option<usize>,
+    pub key: String,
+    pub value: toml::Value,
+}
+
+impl ParamOverride {
+    /// whether the override applies to `day`. Overrides for a single day never apply when there's no telling which day it is
+    pub fn applies_to(&self, day: Option<usize>) -> bool {
+        self.day.is_none() || self.day == day
+    }
+}
+
+/// Parse a `key=value` or `day.key=value` parameter override. Values are read as TOML where possible (e.g. `6`, `true`),
+/// otherwise as strings
+pub fn parse_param(text: &str) -> Result<ParamOverride> {
+    let (key, value) = text
+        .split_once('=')
+        .ok_or_else(|| anyhow!("'{}' should look like key=value or day.key=value", text))?;
+
+    let (day, key) = match key.split_once('.') {
+        Some((day, key)) => {
+            let day = day
+                .trim()
+                .parse()
+                .with_context(|| format!("'{}' is not a day", day.trim()))?;
+            (Some(day), key)
+        }
+        None => (None, key),
+    };
+
+    let value = toml::from_str::<RawParams>(&format!("v = {}", value))
+        .ok()
+        .and_then(|mut t| t.remove("v"))
+        .unwrap_or_else(|| toml::Value::String(value.to_string()));
+
+    Ok(ParamOverride {
+        day,
+        key: key.trim().to_string(),
+        value,
+    })
+}
+
+/// The answers and timings produced by solving a day. All times are in milliseconds
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayReport {
+    pub kind: SolverKind,
+    /// Answers are `None` for parts that weren't asked for
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub parse_ms: f32,
+    /// Per-part times, `None` for a part that wasn't solved. A simultaneous solution's part 2 is timed
+    /// from when it found part 1
+    pub part1_ms: Option<f32>,
+    pub part2_ms: Option<f32>,
+    /// time spent solving both parts
+    pub solve_ms: f32,
+    /// whether part 2 wasn't solved, though the puzzle has one, so the overall times leave some work out
+    pub skipped_part2: bool,
+}
+
+impl DayReport {
+    /// time spent parsing and solving
+    pub fn total_ms(&self) -> f32 {
+        self.parse_ms + self.solve_ms
+    }
+
+    /// whether the report has answers for both parts
+    pub fn is_complete(&self) -> bool {
+        self.part1.is_some() && self.part2.is_some()
+    }
+}
+
+/// A step of solving a day that's done while the rest carries on. Times are in milliseconds
+#[derive(Debug, Clone, PartialEq)]
+pub enum Solved {
+    Input {
+        ms: f32,
+    },
+    /// the answer is `None` if the part wasn't asked for, but had to be solved anyway
+    Part {
+        part: u8,
+        answer: Option<String>,
+        ms: f32,
+    },
+}
+
+/// What a day's puzzle is, whichever style its solution is written in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayInfo {
+    pub year: u16,
+    pub day: usize,
+    pub title: &'static str,
+    /// how many parts the puzzle has. The last day of a year only has one
+    pub parts: u8,
+}
+
+impl DayInfo {
+    /// Solve the day with `solve`, which is only asked for part 1 if that's all the puzzle has
+    fn solve(
+        &self,
+        options: &SolveOptions,
+        solve: impl FnOnce(&SolveOptions) -> Result<DayReport>,
+    ) -> Result<DayReport> {
+        if self.parts > 1 {
+            return solve(options);
+        }
+        if options.parts == Parts::Part2 {
+            return Err(anyhow!("Day {} only has one part", self.day));
+        }
+
+        let options = SolveOptions {
+            parts: Parts::Part1,
+            ..options.clone()
+        };
+        let report = solve(&options)?;
+        Ok(DayReport {
+            skipped_part2: false,
+            ..report
+        })
+    }
+}
+
+/// Any day's solution, linear or simultaneous, so they can all be kept in one registry
+pub trait Solution: Send + Sync {
+    fn info(&self) -> DayInfo;
+
+    /// solve the day, passing each step to `on_solved` as soon as it's done
+    fn solve_streaming(
+        &self,
+        input: &str,
+        options: &SolveOptions,
+        on_solved: &mut dyn FnMut(&Solved),
+    ) -> Result<DayReport>;
+
+    fn solve(&self, input: &str, options: &SolveOptions) -> Result<DayReport> {
+        self.solve_streaming(input, options, &mut |_| ())
+    }
+}
+
+/// a live progress handle if the options ask for one
+fn progress_for(options: &SolveOptions) -> Progress {
+    let progress = if options.progress {
+        Progress::live()
+    } else {
+        Progress::silent()
+    };
+    progress.cancelled_by(&options.cancel)
+}
+
+/// milliseconds since `start`
+fn ms_since(start: Instant) -> f32 {
+    start.elapsed().as_secs_f32() * 1000.0
+}
 
 ////////////// SOLUTION LINEAR
 /// When a day has two parts that must be solved sequentially
 pub trait SolutionLinear<I, S1: Display, S2: Display> {
-    fn load(input: &str) -> Result<I>;
-    fn part1(input: &mut I) -> Result<S1>;
-    fn part2(input: &mut I, part_1_solution: S1) -> Result<S2>;
+    /// Anything that differs between a puzzle's examples and real inputs, such as a grid size,
+    /// and any tunables worth playing with. `Default` gives the real puzzle's values
+    type Params: DeserializeOwned + Default;
+
+    fn load(input: &str, params: &Self::Params) -> Result<I>;
+    fn part1(input: &mut I, params: &Self::Params, progress: &Progress) -> Result<S1>;
+    fn part2(
+        input: &mut I,
+        part_1_solution: S1,
+        params: &Self::Params,
+        progress: &Progress,
+    ) -> Result<S2>;
 }
 
-/// Solve a day where part 2 depends on the output of part 1.
-/// Returns the total time elapsed in milliseconds
+/// Solve a day where part 2 depends on the output of part 1, passing each step to `on_solved` as it's done.
+/// Part 1 is always solved, but part 2 is skipped when only part 1 is asked for
 pub fn solve_linear<S: SolutionLinear<I, S1, S2>, I, S1: Display, S2: Display>(
     input: &str,
-) -> Result<f32> {
-    let start = Instant::now();
+    options: &SolveOptions,
+    on_solved: &mut dyn FnMut(&Solved),
+) -> Result<DayReport> {
+    let parts = options.parts;
+    let params = parse_params(&options.params)?;
+    let progress = progress_for(options);
 
-    let mut input = S::load(input)?;
+    let start = Instant::now();
 
-    let input_loaded = start.elapsed().as_secs_f32() * 1000.0;
+    let mut input = S::load(input, &params)?;
 
-    println!("Parsed input in:\t{}ms", input_loaded);
+    let input_loaded = ms_since(start);
+    on_solved(&Solved::Input { ms: input_loaded });
 
     let p1_start = Instant::now();
 
-    let p1 = S::part1(&mut input)?;
+    progress.begin("Part 1");
+    let p1 = S::part1(&mut input, &params, &progress)?;
+
+    let p1_end = ms_since(p1_start);
+    progress.finish();
 
-    let p1_end = p1_start.elapsed().as_secs_f32() * 1000.0;
+    // part 2 consumes part 1's solution, so render it first
+    let part1 = parts.part1().then(|| p1.to_string());
+    on_solved(&Solved::Part {
+        part: 1,
+        answer: part1.clone(),
+        ms: p1_end,
+    });
 
-    println!("Part 1 Solution: \t{}", p1);
-    println!("Part 1 solved in:\t{}ms", p1_end);
+    let (part2, part2_ms) = if parts.part2() {
+        let p2_start = Instant::now();
 
-    let p2_start = Instant::now();
+        progress.begin("Part 2");
+        let p2 = S::part2(&mut input, p1, &params, &progress)?;
 
-    let p2 = S::part2(&mut input, p1)?;
+        let p2_end = ms_since(p2_start);
+        progress.finish();
 
-    let p2_end = p2_start.elapsed().as_secs_f32() * 1000.0;
+        let part2 = p2.to_string();
+        on_solved(&Solved::Part {
+            part: 2,
+            answer: Some(part2.clone()),
+            ms: p2_end,
+        });
 
-    println!("Part 2 Solution: \t{}", p2);
-    println!("Part 2 solved in:\t{}ms", p2_end);
+        (Some(part2), Some(p2_end))
+    } else {
+        (None, None)
+    };
 
-    let solved_in = input_loaded + p1_end + p2_end;
+    Ok(DayReport {
+        kind: SolverKind::Linear,
+        part1,
+        part2,
+        parse_ms: input_loaded,
+        part1_ms: Some(p1_end),
+        part2_ms,
+        solve_ms: p1_end + part2_ms.unwrap_or_default(),
+        skipped_part2: part2_ms.is_none(),
+    })
+}
 
-    println!("Overall time:\t\t{}ms", solved_in);
+/// Ties a [`Solution`] wrapper to its solver's types without holding any of them
+type SolverTypes<S, I, S1, S2> = PhantomData<fn() -> (S, I, S1, S2)>;
 
-    Ok(solved_in)
+/// A [`SolutionLinear`] as a [`Solution`], e.g. `Linear::<Day01Solution, _, _, _>::new(info)`
+pub struct Linear<S, I, S1, S2> {
+    info: DayInfo,
+    solver: SolverTypes<S, I, S1, S2>,
+}
+
+impl<S, I, S1, S2> Linear<S, I, S1, S2> {
+    pub fn new(info: DayInfo) -> Self {
+        Self {
+            info,
+            solver: PhantomData,
+        }
+    }
+}
+
+impl<S: SolutionLinear<I, S1, S2>, I, S1: Display, S2: Display> Solution for Linear<S, I, S1, S2> {
+    fn info(&self) -> DayInfo {
+        self.info
+    }
+
+    fn solve_streaming(
+        &self,
+        input: &str,
+        options: &SolveOptions,
+        on_solved: &mut dyn FnMut(&Solved),
+    ) -> Result<DayReport> {
+        self.info.solve(options, |options| {
+            solve_linear::<S, I, S1, S2>(input, options, on_solved)
+        })
+    }
 }
 
 ////////////// SOLUTION SIMULTANEOUS
 /// When a day has two parts that can be solved simultaneously
 pub trait SolutionSimultaneous<I, S1: Display, S2: Display> {
-    fn load(input: &str) -> Result<I>;
-    fn solve(input: I) -> Result<(S1, S2)>;
+    /// Anything that differs between a puzzle's examples and real inputs, such as a grid size,
+    /// and any tunables worth playing with. `Default` gives the real puzzle's values
+    type Params: DeserializeOwned + Default;
+
+    fn load(input: &str, params: &Self::Params) -> Result<I>;
+    /// solve both parts, handing each answer to `sink` as soon as it's found
+    fn solve(
+        input: I,
+        sink: &mut PartSink<S1, S2>,
+        params: &Self::Params,
+        progress: &Progress,
+    ) -> Result<()>;
+}
+
+/// Where a simultaneous solution sends its answers. Each part is timed from when the last one was found,
+/// and passed on straight away so it can be shown while the other is still being solved
+pub struct PartSink<'a, S1, S2> {
+    parts: Parts,
+    lap: Instant,
+    part1: Option<(String, f32)>,
+    part2: Option<(String, f32)>,
+    on_solved: &'a mut dyn FnMut(&Solved),
+    answers: PhantomData<fn(S1, S2)>,
 }
 
-/// Solve a day where part 1 and part 2 can be solved simultaneously
-/// Returns the total time elapsed in milliseconds
+impl<'a, S1: Display, S2: Display> PartSink<'a, S1, S2> {
+    pub fn new(parts: Parts, on_solved: &'a mut dyn FnMut(&Solved)) -> Self {
+        Self {
+            parts,
+            lap: Instant::now(),
+            part1: None,
+            part2: None,
+            on_solved,
+            answers: PhantomData,
+        }
+    }
+
+    pub fn part1(&mut self, answer: S1) {
+        let found = self.found(1, answer.to_string(), self.parts.part1());
+        self.part1 = Some(found);
+    }
+
+    pub fn part2(&mut self, answer: S2) {
+        let found = self.found(2, answer.to_string(), self.parts.part2());
+        self.part2 = Some(found);
+    }
+
+    /// whether part 2 was asked for. If not, a solution can stop once it has part 1
+    pub fn wants_part2(&self) -> bool {
+        self.parts.part2()
+    }
+
+    fn found(&mut self, part: u8, answer: String, wanted: bool) -> (String, f32) {
+        let ms = ms_since(self.lap);
+        self.lap = Instant::now();
+        (self.on_solved)(&Solved::Part {
+            part,
+            answer: wanted.then(|| answer.clone()),
+            ms,
+        });
+        (answer, ms)
+    }
+}
+
+/// Solve a day where part 1 and part 2 can be solved simultaneously, passing each step to `on_solved` as it's done.
+/// Only the answers asked for are reported, and a solution may skip part 2 when it isn't
 pub fn solve_simultaneous<S: SolutionSimultaneous<I, S1, S2>, I, S1: Display, S2: Display>(
     input: &str,
-) -> Result<f32> {
-    let start = Instant::now();
+    options: &SolveOptions,
+    on_solved: &mut dyn FnMut(&Solved),
+) -> Result<DayReport> {
+    let parts = options.parts;
+    let params = parse_params(&options.params)?;
+    let progress = progress_for(options);
 
-    let input = S::load(input)?;
+    let start = Instant::now();
 
-    let input_loaded = start.elapsed().as_secs_f32() * 1000.0;
+    let input = S::load(input, &params)?;
 
-    println!("Parsed input in:\t{}ms", input_loaded);
+    let input_loaded = ms_since(start);
+    on_solved(&Solved::Input { ms: input_loaded });
 
     let start_solving = Instant::now();
 
-    let (p1, p2) = S::solve(input)?;
+    // clear the progress line before anything else is shown
+    let mut on_solved = |solved: &Solved| {
+        progress.finish();
+        on_solved(solved);
+    };
+    let mut sink = PartSink::new(parts, &mut on_solved);
+    progress.begin("Solving");
+    S::solve(input, &mut sink, &params, &progress)?;
+    progress.finish();
+
+    let solved = ms_since(start_solving);
+
+    let (p1, part1_ms) = sink
+        .part1
+        .ok_or_else(|| anyhow!("The solution never found part 1"))?;
+    if parts.part2() && sink.part2.is_none() {
+        return Err(anyhow!("The solution never found part 2"));
+    }
+    let (part2, part2_ms) = sink.part2.unzip();
+
+    Ok(DayReport {
+        kind: SolverKind::Simultaneous,
+        part1: parts.part1().then_some(p1),
+        part2: part2.filter(|_| parts.part2()),
+        parse_ms: input_loaded,
+        part1_ms: Some(part1_ms),
+        part2_ms,
+        solve_ms: solved,
+        skipped_part2: part2_ms.is_none(),
+    })
+}
+
+/// A [`SolutionSimultaneous`] as a [`Solution`], e.g. `Simultaneous::<Day16Solution, _, _, _>::new(info)`
+pub struct Simultaneous<S, I, S1, S2> {
+    info: DayInfo,
+    solver: SolverTypes<S, I, S1, S2>,
+}
+
+impl<S, I, S1, S2> Simultaneous<S, I, S1, S2> {
+    pub fn new(info: DayInfo) -> Self {
+        Self {
+            info,
+            solver: PhantomData,
+        }
+    }
+}
+
+impl<S: SolutionSimultaneous<I, S1, S2>, I, S1: Display, S2: Display> Solution
+    for Simultaneous<S, I, S1, S2>
+{
+    fn info(&self) -> DayInfo {
+        self.info
+    }
+
+    fn solve_streaming(
+        &self,
+        input: &str,
+        options: &SolveOptions,
+        on_solved: &mut dyn FnMut(&Solved),
+    ) -> Result<DayReport> {
+        self.info.solve(options, |options| {
+            solve_simultaneous::<S, I, S1, S2>(input, options, on_solved)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use serde::Deserialize;
+
+    use super::{
+        parse_param, parse_params, solve_linear, solve_simultaneous, DayInfo, Linear, NoParams,
+        ParamOverride, Parts, RawParams, Solution, SolveOptions, Solved, SolverKind,
+    };
+    use crate::solutions::templates::{
+        linear_template::ExampleSolutionLinear, simultaneous_template::ExampleSolutionSimultaneous,
+    };
+
+    #[test]
+    fn test_linear_report() {
+        let report = solve_linear::<ExampleSolutionLinear, _, _, _>(
+            "[1,2,3]",
+            &SolveOptions::new(Parts::Both),
+            &mut |_| (),
+        )
+        .unwrap();
+
+        assert_eq!(SolverKind::Linear, report.kind);
+        assert_eq!(Some("6".to_string()), report.part1);
+        assert_eq!(Some("18".to_string()), report.part2);
+        assert!(report.part1_ms.is_some() && report.part2_ms.is_some());
+    }
+
+    #[test]
+    fn test_linear_parts() {
+        let part1 = solve_linear::<ExampleSolutionLinear, _, _, _>(
+            "[1,2,3]",
+            &SolveOptions::new(Parts::Part1),
+            &mut |_| (),
+        )
+        .unwrap();
+        assert_eq!(Some("6".to_string()), part1.part1);
+        assert_eq!((None, None), (part1.part2, part1.part2_ms));
+
+        // part 1 is still solved and timed, but its answer is left out
+        let part2 = solve_linear::<ExampleSolutionLinear, _, _, _>(
+            "[1,2,3]",
+            &SolveOptions::new(Parts::Part2),
+            &mut |_| (),
+        )
+        .unwrap();
+        assert_eq!(None, part2.part1);
+        assert!(part2.part1_ms.is_some());
+        assert_eq!(Some("18".to_string()), part2.part2);
+    }
+
+    #[test]
+    fn test_simultaneous_report() {
+        let report = solve_simultaneous::<ExampleSolutionSimultaneous, _, _, _>(
+            "[6,5,4,2,3,5,8]",
+            &SolveOptions::new(Parts::Both),
+            &mut |_| (),
+        )
+        .unwrap();
+
+        assert_eq!(SolverKind::Simultaneous, report.kind);
+        assert_eq!(Some("3".to_string()), report.part1);
+        assert_eq!(Some("5".to_string()), report.part2);
+        assert!(report.part1_ms.is_some() && report.part2_ms.is_some());
+
+        let part2 = solve_simultaneous::<ExampleSolutionSimultaneous, _, _, _>(
+            "[6,5,4,2,3,5,8]",
+            &SolveOptions::new(Parts::Part2),
+            &mut |_| (),
+        )
+        .unwrap();
+        assert_eq!(None, part2.part1);
+        assert_eq!(Some("5".to_string()), part2.part2);
+    }
+
+    #[test]
+    fn test_one_part_day() {
+        let day25 = Linear::<ExampleSolutionLinear, _, _, _>::new(DayInfo {
+            year: 2024,
+            day: 25,
+            title: "Example",
+            parts: 1,
+        });
+
+        let report = day25
+            .solve("[1,2,3]", &SolveOptions::new(Parts::Both))
+            .unwrap();
+        assert_eq!(Some("6".to_string()), report.part1);
+        assert_eq!((None, None), (report.part2, report.part2_ms));
+        // there's no part 2 to skip, so the overall times cover everything
+        assert!(!report.skipped_part2);
+
+        let err = day25
+            .solve("[1,2,3]", &SolveOptions::new(Parts::Part2))
+            .unwrap_err();
+        assert_eq!("Day 25 only has one part", err.to_string());
+    }
+
+    /// the steps passed on while solving, without their times
+    fn steps(solve: impl FnOnce(&mut dyn FnMut(&Solved))) -> Vec<(u8, Option<String>)> {
+        let mut steps = vec![];
+        solve(&mut |s| match s {
+            Solved::Input { .. } => steps.push((0, None)),
+            Solved::Part { part, answer, .. } => steps.push((*part, answer.clone())),
+        });
+        steps
+    }
+
+    #[rstest]
+    #[case(Parts::Both, vec![(0, None), (1, Some("15")), (2, Some("75"))])]
+    #[case(Parts::Part1, vec![(0, None), (1, Some("15"))])]
+    #[case(Parts::Part2, vec![(0, None), (1, None), (2, Some("75"))])]
+    fn validate_linear_streaming(#[case] parts: Parts, #[case] expected: Vec<(u8, Option<&str>)>) {
+        let expected: Vec<_> = expected
+            .into_iter()
+            .map(|(p, a)| (p, a.map(str::to_string)))
+            .collect();
+
+        let streamed = steps(|on_solved| {
+            solve_linear::<ExampleSolutionLinear, _, _, _>(
+                "[1,2,3,4,5]",
+                &SolveOptions::new(parts),
+                on_solved,
+            )
+            .unwrap();
+        });
+
+        assert_eq!(expected, streamed);
+    }
+
+    #[test]
+    fn test_simultaneous_streaming() {
+        let streamed = steps(|on_solved| {
+            solve_simultaneous::<ExampleSolutionSimultaneous, _, _, _>(
+                "[6,5,4,2,3,5,8]",
+                &SolveOptions::new(Parts::Part2),
+                on_solved,
+            )
+            .unwrap();
+        });
+
+        assert_eq!(
+            vec![(0, None), (1, None), (2, Some("5".to_string()))],
+            streamed
+        );
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(default, deny_unknown_fields)]
+    struct GridParams {
+        size: isize,
+        fallen: usize,
+    }
 
-    let solved = start_solving.elapsed().as_secs_f32() * 1000.0;
+    impl Default for GridParams {
+        fn default() -> Self {
+            Self {
+                size: 70,
+                fallen: 1024,
+            }
+        }
+    }
 
-    println!("Part 1 Solution: \t{}", p1);
-    println!("Part 2 Solution: \t{}", p2);
+    #[test]
+    fn test_parse_params() {
+        let raw: RawParams = toml::from_str("size = 6").unwrap();
+        assert_eq!(
+            GridParams {
+                size: 6,
+                fallen: 1024
+            },
+            parse_params(&raw).unwrap()
+        );
+        assert_eq!(
+            GridParams::default(),
+            parse_params(&RawParams::new()).unwrap()
+        );
 
-    println!("Solved in:\t\t{}ms", solved);
+        let typo: RawParams = toml::from_str("sise = 6").unwrap();
+        assert!(parse_params::<GridParams>(&typo).is_err());
+        assert!(parse_params::<NoParams>(&raw).is_err());
+        assert_eq!(NoParams {}, parse_params(&RawParams::new()).unwrap());
+    }
 
-    let solved_in = input_loaded + solved;
+    #[rstest]
+    #[case("size=6", None, "size", toml::Value::Integer(6))]
+    #[case("ratio = 0.5", None, "ratio", toml::Value::Float(0.5))]
+    #[case("name=alice", None, "name", toml::Value::String("alice".to_string()))]
+    #[case("name=\"bob\"", None, "name", toml::Value::String("bob".to_string()))]
+    #[case("11.blinks=40", Some(11), "blinks", toml::Value::Integer(40))]
+    fn validate_parse_param(
+        #[case] input: &str,
+        #[case] day: Option<usize>,
+        #[case] key: &str,
+        #[case] value: toml::Value,
+    ) {
+        let expected = ParamOverride {
+            day,
+            key: key.to_string(),
+            value,
+        };
+        assert_eq!(expected, parse_param(input).unwrap());
+    }
 
-    println!("Overall time:\t\t{}ms", solved_in);
+    #[test]
+    fn test_param_scope() {
+        let every_day = parse_param("size=6").unwrap();
+        let day_11 = parse_param("11.blinks=40").unwrap();
 
-    Ok(solved_in)
+        assert!(every_day.applies_to(Some(18)) && every_day.applies_to(None));
+        assert!(day_11.applies_to(Some(11)));
+        assert!(!day_11.applies_to(Some(12)) && !day_11.applies_to(None));
+        assert!(parse_param("eleven.blinks=40").is_err());
+    }
 }